@@ -1,4 +1,6 @@
-use crate::syntax::SyntaxKind;
+use rowan::NodeOrToken;
+
+use crate::{syntax::SyntaxKind, SyntaxElement};
 
 use super::{filter_token, AffiliatedKeyword, Token};
 
@@ -46,7 +48,67 @@ impl AffiliatedKeyword {
     pub fn value(&self) -> Option<Token> {
         self.syntax
             .children_with_tokens()
+            .skip_while(|e| e.kind() != SyntaxKind::COLON)
             .filter_map(filter_token(SyntaxKind::TEXT))
             .last()
     }
+
+    /// Returns raw value of this keyword, without trailing whitespace
+    ///
+    /// Unlike [`AffiliatedKeyword::value`], it includes every object when
+    /// the value is parsed, see [`AffiliatedKeyword::objects`].
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::AffiliatedKeyword};
+    ///
+    /// let keyword = Org::parse("#+CAPTION: *bold* value  \nabc").first_node::<AffiliatedKeyword>().unwrap();
+    /// assert_eq!(keyword.value_raw(), " *bold* value");
+    /// assert_eq!(keyword.value().unwrap(), " value");
+    /// let keyword = Org::parse("#+NAME:\nabc").first_node::<AffiliatedKeyword>().unwrap();
+    /// assert_eq!(keyword.value_raw(), "");
+    /// ```
+    pub fn value_raw(&self) -> String {
+        let mut value = String::new();
+        for element in self.objects() {
+            match element {
+                NodeOrToken::Node(node) => value.push_str(&node.to_string()),
+                NodeOrToken::Token(token) => value.push_str(token.text()),
+            }
+        }
+        value
+    }
+
+    /// Returns value of this keyword as objects
+    ///
+    /// Values of keywords in [`ParseConfig::parsed_keywords`](crate::ParseConfig::parsed_keywords),
+    /// like `CAPTION`, are parsed as objects. Others contain a single text token.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::AffiliatedKeyword, SyntaxKind};
+    ///
+    /// let keyword = Org::parse("#+CAPTION: *bold* value\nabc").first_node::<AffiliatedKeyword>().unwrap();
+    /// let objects = keyword.objects().collect::<Vec<_>>();
+    /// assert_eq!(objects.len(), 3);
+    /// assert_eq!(objects[1].kind(), SyntaxKind::BOLD);
+    ///
+    /// let keyword = Org::parse("#+NAME: *bold* value\nabc").first_node::<AffiliatedKeyword>().unwrap();
+    /// let objects = keyword.objects().collect::<Vec<_>>();
+    /// assert_eq!(objects.len(), 1);
+    /// assert_eq!(objects[0].kind(), SyntaxKind::TEXT);
+    /// ```
+    pub fn objects(&self) -> impl Iterator<Item = SyntaxElement> {
+        let mut elements = self
+            .syntax
+            .children_with_tokens()
+            .skip_while(|e| e.kind() != SyntaxKind::COLON)
+            .skip(1)
+            .collect::<Vec<_>>();
+        while elements
+            .last()
+            .is_some_and(|e| matches!(e.kind(), SyntaxKind::WHITESPACE | SyntaxKind::NEW_LINE))
+        {
+            elements.pop();
+        }
+        elements.into_iter()
+    }
 }
@@ -1,8 +1,309 @@
 use super::{
-    filter_token, CenterBlock, CommentBlock, DynBlock, ExampleBlock, ExportBlock, QuoteBlock,
-    SourceBlock, SpecialBlock, SyntaxKind, Token, VerseBlock,
+    filter_token, AffiliatedKeyword, CenterBlock, CommentBlock, DynBlock, ExampleBlock,
+    ExportBlock, QuoteBlock, SourceBlock, SpecialBlock, SyntaxKind, Token, VerseBlock,
 };
-use rowan::TextSize;
+use rowan::{ast::AstNode, TextSize};
+
+use crate::{
+    syntax::combinator::{node, token},
+    SyntaxNode,
+};
+
+/// Line numbering of a source or example block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberLines {
+    /// `-n N`: numbering starts at given number
+    New(u32),
+    /// `+n N`: numbering continues from the previous numbered block, plus
+    /// given offset
+    Continued(u32),
+}
+
+impl NumberLines {
+    /// Returns the number of the first line, given the number of the last
+    /// line of the previous numbered block
+    pub fn first_line(&self, previous: u32) -> u32 {
+        match *self {
+            NumberLines::New(start) => start,
+            NumberLines::Continued(offset) => previous + offset + 1,
+        }
+    }
+}
+
+/// Parsed switches of a source or example block, like `-n -r -l "[%s]"`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockSwitches {
+    /// Line numbering, set by `-n` or `+n`
+    pub number_lines: Option<NumberLines>,
+    /// `false` if coderef labels are removed from the code, set by `-r`
+    pub retain_labels: bool,
+    /// `false` if coderef links use labels instead of line numbers, set by `-k`
+    pub use_labels: bool,
+    /// `true` if indentation is preserved on export, set by `-i`
+    pub preserve_indent: bool,
+    /// Format of coderef labels, set by `-l`. Defaults to `(ref:%s)`
+    pub label_format: String,
+}
+
+impl Default for BlockSwitches {
+    fn default() -> Self {
+        BlockSwitches {
+            number_lines: None,
+            retain_labels: true,
+            use_labels: true,
+            preserve_indent: false,
+            label_format: "(ref:%s)".into(),
+        }
+    }
+}
+
+impl BlockSwitches {
+    /// Parses block switches
+    ///
+    /// Unknown switches are ignored.
+    ///
+    /// ```rust
+    /// use orgize::ast::{BlockSwitches, NumberLines};
+    ///
+    /// let switches = BlockSwitches::parse(r#"-n 10 -r -l "[%s]""#);
+    /// assert_eq!(switches.number_lines, Some(NumberLines::New(10)));
+    /// assert!(!switches.retain_labels);
+    /// assert_eq!(switches.label_format, "[%s]");
+    ///
+    /// let switches = BlockSwitches::parse("+n -i");
+    /// assert_eq!(switches.number_lines, Some(NumberLines::Continued(0)));
+    /// assert!(switches.preserve_indent);
+    ///
+    /// assert_eq!(BlockSwitches::parse(""), BlockSwitches::default());
+    /// ```
+    pub fn parse(s: &str) -> BlockSwitches {
+        let mut switches = BlockSwitches::default();
+        let mut words = s.split_whitespace().peekable();
+
+        while let Some(word) = words.next() {
+            match word {
+                "-n" | "+n" => {
+                    let value = words.peek().and_then(|w| w.parse::<u32>().ok());
+                    if value.is_some() {
+                        words.next();
+                    }
+                    switches.number_lines = Some(if word == "-n" {
+                        NumberLines::New(value.unwrap_or(1))
+                    } else {
+                        NumberLines::Continued(value.unwrap_or(0))
+                    });
+                }
+                "-r" => switches.retain_labels = false,
+                "-k" => switches.use_labels = false,
+                "-i" => switches.preserve_indent = true,
+                "-l" => {
+                    let Some(first) = words.next() else { break };
+                    let mut format = first.to_string();
+                    if format.starts_with('"') {
+                        while !(format.len() > 1 && format.ends_with('"')) {
+                            let Some(next) = words.next() else { break };
+                            format.push(' ');
+                            format.push_str(next);
+                        }
+                        format = format.trim_matches('"').to_string();
+                    }
+                    switches.label_format = format;
+                }
+                _ => {}
+            }
+        }
+
+        switches
+    }
+
+    /// Finds the coderef label at the end of given line
+    ///
+    /// Returns the byte offset where the label, including whitespace before
+    /// it, starts, and the label name.
+    ///
+    /// ```rust
+    /// use orgize::ast::BlockSwitches;
+    ///
+    /// let switches = BlockSwitches::default();
+    /// assert_eq!(switches.find_label("let a = 1; (ref:init)\n"), Some((10, "init")));
+    /// assert_eq!(switches.find_label("let a = 1;"), None);
+    ///
+    /// let switches = BlockSwitches::parse("-l [%s]");
+    /// assert_eq!(switches.find_label("x [jump]"), Some((1, "jump")));
+    /// ```
+    pub fn find_label<'a>(&self, line: &'a str) -> Option<(usize, &'a str)> {
+        let (prefix, suffix) = self.label_format.split_once("%s")?;
+        let line = line.trim_end();
+        let rest = line.strip_suffix(suffix)?;
+        let start = rest.rfind(prefix)?;
+        let label = &rest[start + prefix.len()..];
+
+        let valid = label
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | ' '))
+            && !label.starts_with(' ')
+            && !label.is_empty();
+
+        valid.then(|| (rest[..start].trim_end().len(), label))
+    }
+}
+
+/// How results are collected, part of [`ResultsParams`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultsCollection {
+    /// `value`: the value of the last expression
+    #[default]
+    Value,
+    /// `output`: everything printed to standard output
+    Output,
+}
+
+/// How results are interpreted, part of [`ResultsParams`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultsType {
+    /// `table` or `vector`
+    Table,
+    /// `list`
+    List,
+    /// `scalar` or `verbatim`
+    Scalar,
+    /// `file`: results are a path to a file
+    File,
+}
+
+/// How results are wrapped when inserted, part of [`ResultsParams`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultsFormat {
+    /// `raw`: inserted as org-mode text
+    Raw,
+    /// `code`: wrapped in a source block
+    Code,
+    /// `drawer`: wrapped in a `RESULTS` drawer
+    Drawer,
+    /// `html`: wrapped in an html export block
+    Html,
+    /// `latex`: wrapped in a latex export block
+    Latex,
+    /// `link`
+    Link,
+    /// `graphics`
+    Graphics,
+    /// `org`: wrapped in an org source block
+    Org,
+    /// `pp`: pretty-printed
+    Pp,
+}
+
+/// How results are inserted into the buffer, part of [`ResultsParams`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultsHandling {
+    /// `replace`: previous results are replaced
+    #[default]
+    Replace,
+    /// `silent`: results are echoed but not inserted
+    Silent,
+    /// `none`: results are neither echoed nor inserted
+    None,
+    /// `discard`: results are ignored
+    Discard,
+    /// `append`: results are inserted after previous results
+    Append,
+    /// `prepend`: results are inserted before previous results
+    Prepend,
+}
+
+/// Parsed `:results` header argument of a source block
+///
+/// Each value belongs to one of four groups. When a group is given more
+/// than once, the last value wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResultsParams {
+    /// Defaults to [`ResultsCollection::Value`]
+    pub collection: ResultsCollection,
+    /// `None` if not specified, in which case it's inferred from results
+    pub ty: Option<ResultsType>,
+    /// `None` if not specified
+    pub format: Option<ResultsFormat>,
+    /// Defaults to [`ResultsHandling::Replace`]
+    pub handling: ResultsHandling,
+}
+
+impl ResultsParams {
+    /// Parses the value of `:results` header argument
+    ///
+    /// Unknown values are ignored.
+    ///
+    /// ```rust
+    /// use orgize::ast::{ResultsCollection, ResultsFormat, ResultsHandling, ResultsParams, ResultsType};
+    ///
+    /// let params = ResultsParams::parse("output verbatim drawer append");
+    /// assert_eq!(params.collection, ResultsCollection::Output);
+    /// assert_eq!(params.ty, Some(ResultsType::Scalar));
+    /// assert_eq!(params.format, Some(ResultsFormat::Drawer));
+    /// assert_eq!(params.handling, ResultsHandling::Append);
+    ///
+    /// let params = ResultsParams::parse("table raw table list");
+    /// assert_eq!(params.ty, Some(ResultsType::List));
+    /// assert_eq!(params.format, Some(ResultsFormat::Raw));
+    ///
+    /// assert_eq!(ResultsParams::parse(""), ResultsParams::default());
+    /// ```
+    pub fn parse(s: &str) -> ResultsParams {
+        let mut params = ResultsParams::default();
+        params.merge(s);
+        params
+    }
+
+    fn merge(&mut self, s: &str) {
+        for word in s.split_whitespace() {
+            match word {
+                "value" => self.collection = ResultsCollection::Value,
+                "output" => self.collection = ResultsCollection::Output,
+
+                "table" | "vector" => self.ty = Some(ResultsType::Table),
+                "list" => self.ty = Some(ResultsType::List),
+                "scalar" | "verbatim" => self.ty = Some(ResultsType::Scalar),
+                "file" => self.ty = Some(ResultsType::File),
+
+                "raw" => self.format = Some(ResultsFormat::Raw),
+                "code" => self.format = Some(ResultsFormat::Code),
+                "drawer" => self.format = Some(ResultsFormat::Drawer),
+                "html" => self.format = Some(ResultsFormat::Html),
+                "latex" => self.format = Some(ResultsFormat::Latex),
+                "link" => self.format = Some(ResultsFormat::Link),
+                "graphics" => self.format = Some(ResultsFormat::Graphics),
+                "org" => self.format = Some(ResultsFormat::Org),
+                "pp" => self.format = Some(ResultsFormat::Pp),
+
+                "replace" => self.handling = ResultsHandling::Replace,
+                "silent" => self.handling = ResultsHandling::Silent,
+                "none" => self.handling = ResultsHandling::None,
+                "discard" => self.handling = ResultsHandling::Discard,
+                "append" => self.handling = ResultsHandling::Append,
+                "prepend" => self.handling = ResultsHandling::Prepend,
+
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Splits header arguments like `:results output :exports code` into keys
+/// and values
+fn parse_header_arguments(s: &str) -> Vec<(String, String)> {
+    let mut arguments: Vec<(String, String)> = vec![];
+    for word in s.split_whitespace() {
+        if word.starts_with(':') {
+            arguments.push((word.to_string(), String::new()));
+        } else if let Some((_, value)) = arguments.last_mut() {
+            if !value.is_empty() {
+                value.push(' ');
+            }
+            value.push_str(word);
+        }
+    }
+    arguments
+}
 
 impl SourceBlock {
     /// ```rust
@@ -49,6 +350,127 @@ impl SourceBlock {
             .find_map(filter_token(SyntaxKind::SRC_BLOCK_SWITCHES))
     }
 
+    /// Returns parsed switches
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{SourceBlock, NumberLines}};
+    ///
+    /// let block = Org::parse("#+begin_src rust -n 5 -r\n#+end_src").first_node::<SourceBlock>().unwrap();
+    /// let switches = block.block_switches();
+    /// assert_eq!(switches.number_lines, Some(NumberLines::New(5)));
+    /// assert!(!switches.retain_labels);
+    ///
+    /// let block = Org::parse("#+begin_src rust\n#+end_src").first_node::<SourceBlock>().unwrap();
+    /// assert_eq!(block.block_switches().number_lines, None);
+    /// ```
+    pub fn block_switches(&self) -> BlockSwitches {
+        self.switches()
+            .map(|s| BlockSwitches::parse(&s))
+            .unwrap_or_default()
+    }
+
+    /// Returns parsed `:results` header argument
+    ///
+    /// Header arguments in `#+HEADER` keywords are read first, followed by
+    /// those in the block parameters, so the latter take precedence.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{ResultsCollection, ResultsHandling, ResultsType, SourceBlock}};
+    ///
+    /// let block = Org::parse(r#"#+HEADER: :results output table :exports both
+    /// #+BEGIN_SRC python :results list silent :session
+    /// print(1)
+    /// #+END_SRC"#).first_node::<SourceBlock>().unwrap();
+    /// let params = block.results_params();
+    /// assert_eq!(params.collection, ResultsCollection::Output);
+    /// assert_eq!(params.ty, Some(ResultsType::List));
+    /// assert_eq!(params.format, None);
+    /// assert_eq!(params.handling, ResultsHandling::Silent);
+    ///
+    /// let block = Org::parse("#+BEGIN_SRC sh\n#+END_SRC").first_node::<SourceBlock>().unwrap();
+    /// assert_eq!(block.results_params().collection, ResultsCollection::Value);
+    /// ```
+    pub fn results_params(&self) -> ResultsParams {
+        let mut params = ResultsParams::default();
+        for (key, value) in self.header_arguments() {
+            if key.eq_ignore_ascii_case(":results") {
+                params.merge(&value);
+            }
+        }
+        params
+    }
+
+    /// Returns header arguments from `#+HEADER` keywords and the block
+    /// parameters, in that order
+    ///
+    /// Keys keep their leading colon. Values of repeated keys are all
+    /// returned, so later ones usually take precedence.
+    ///
+    /// A value spanning several words is returned as one token, with words
+    /// separated by a single space, and a key without value is paired with
+    /// an empty token. So the returned tokens don't belong to the syntax
+    /// tree of this block, but to a tree built for them.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::SourceBlock};
+    ///
+    /// let block = Org::parse("#+HEADER: :var x=1\n#+BEGIN_SRC rust :results value :exports code\nfoo\n#+END_SRC")
+    ///     .first_node::<SourceBlock>()
+    ///     .unwrap();
+    /// assert_eq!(block.language().unwrap(), "rust");
+    /// assert_eq!(block.value(), "foo\n");
+    /// let arguments = block
+    ///     .header_arguments()
+    ///     .map(|(key, value)| format!("{key}={value}"))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(arguments, [":var=x=1", ":results=value", ":exports=code"]);
+    ///
+    /// let block = Org::parse("#+BEGIN_SRC sh :results output replace :cache\n#+END_SRC")
+    ///     .first_node::<SourceBlock>()
+    ///     .unwrap();
+    /// let (key, value) = block.header_arguments().next().unwrap();
+    /// assert_eq!(key, ":results");
+    /// assert_eq!(value, "output replace");
+    /// let (key, value) = block.header_arguments().nth(1).unwrap();
+    /// assert_eq!(key, ":cache");
+    /// assert_eq!(value, "");
+    ///
+    /// let block = Org::parse("#+BEGIN_SRC rust\n#+END_SRC").first_node::<SourceBlock>().unwrap();
+    /// assert_eq!(block.header_arguments().count(), 0);
+    /// assert_eq!(block.value(), "");
+    /// ```
+    pub fn header_arguments(&self) -> impl Iterator<Item = (Token, Token)> {
+        let headers = self
+            .syntax
+            .children()
+            .filter_map(AffiliatedKeyword::cast)
+            .filter(|k| {
+                k.key().eq_ignore_ascii_case("HEADER") || k.key().eq_ignore_ascii_case("HEADERS")
+            })
+            .filter_map(|k| k.value())
+            .map(|v| v.to_string());
+        let arguments: Vec<_> = headers
+            .chain(self.parameters().map(|p| p.to_string()))
+            .flat_map(|s| parse_header_arguments(&s))
+            .flat_map(|(key, value)| {
+                [
+                    token(SyntaxKind::TEXT, &key),
+                    token(SyntaxKind::TEXT, &value),
+                ]
+            })
+            .collect();
+
+        let root = SyntaxNode::new_root(
+            node(SyntaxKind::SRC_BLOCK_PARAMETERS, arguments)
+                .into_node()
+                .unwrap(),
+        );
+        let mut tokens = root
+            .children_with_tokens()
+            .filter_map(|elem| elem.into_token());
+        std::iter::from_fn(move || Some((Token(tokens.next()?), Token(tokens.next()?))))
+    }
+
     /// ```rust
     /// use orgize::{Org, ast::SourceBlock};
     ///
@@ -99,6 +521,63 @@ impl SourceBlock {
     }
 }
 
+impl ExampleBlock {
+    /// Returns switches text
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::ExampleBlock};
+    ///
+    /// let block = Org::parse("#+begin_example -n -r\n#+end_example").first_node::<ExampleBlock>().unwrap();
+    /// assert_eq!(block.switches().unwrap(), "-n -r");
+    ///
+    /// let block = Org::parse("#+begin_example\n#+end_example").first_node::<ExampleBlock>().unwrap();
+    /// assert!(block.switches().is_none());
+    /// ```
+    pub fn switches(&self) -> Option<String> {
+        let switches = self
+            .syntax
+            .children()
+            .find(|e| e.kind() == SyntaxKind::BLOCK_BEGIN)?
+            .children_with_tokens()
+            .filter_map(filter_token(SyntaxKind::TEXT))
+            .nth(2)?;
+        let switches = switches.trim();
+        (!switches.is_empty()).then(|| switches.to_string())
+    }
+
+    /// Returns parsed switches
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{ExampleBlock, NumberLines}};
+    ///
+    /// let block = Org::parse("#+begin_example +n 10\n#+end_example").first_node::<ExampleBlock>().unwrap();
+    /// assert_eq!(block.block_switches().number_lines, Some(NumberLines::Continued(10)));
+    /// ```
+    pub fn block_switches(&self) -> BlockSwitches {
+        self.switches()
+            .map(|s| BlockSwitches::parse(&s))
+            .unwrap_or_default()
+    }
+
+    /// Return unescaped example string
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::ExampleBlock};
+    ///
+    /// let block = Org::parse("#+begin_example\n,* foo\nbar\n#+end_example").first_node::<ExampleBlock>().unwrap();
+    /// assert_eq!(block.value(), "* foo\nbar\n");
+    /// ```
+    pub fn value(&self) -> String {
+        self.syntax
+            .children()
+            .find(|e| e.kind() == SyntaxKind::BLOCK_CONTENT)
+            .into_iter()
+            .flat_map(|n| n.children_with_tokens())
+            .filter_map(filter_token(SyntaxKind::TEXT))
+            .fold(String::new(), |acc, value| acc + &value)
+    }
+}
+
 impl ExportBlock {
     /// ```rust
     /// use orgize::{Org, ast::ExportBlock};
@@ -163,7 +642,7 @@ macro_rules! impl_content_border {
                     .find(|n| n.kind() == SyntaxKind::BLOCK_BEGIN)
                     .map(|n| n.text_range().end())
                     .unwrap_or_else(|| {
-                        debug_assert!(false, "block must contains BLOCK_BEGIN");
+                        debug_assert!(false, "block must contain BLOCK_BEGIN");
                         TextSize::default()
                     })
             }
@@ -175,7 +654,7 @@ macro_rules! impl_content_border {
                     .find(|n| n.kind() == SyntaxKind::BLOCK_END)
                     .map(|n| n.text_range().start())
                     .unwrap_or_else(|| {
-                        debug_assert!(false, "block must contains BLOCK_END");
+                        debug_assert!(false, "block must contain BLOCK_END");
                         TextSize::default()
                     })
             }
@@ -192,3 +671,62 @@ impl_content_border!(QuoteBlock);
 impl_content_border!(SpecialBlock);
 impl_content_border!(VerseBlock);
 impl_content_border!(DynBlock);
+
+macro_rules! impl_block_name {
+    ($block:ident) => {
+        impl $block {
+            /// Returns block name, after `#+BEGIN_`, in its original case
+            ///
+            /// The block is only closed by an `#+END_` line with the same
+            /// name, compared case-insensitively. Blocks without a matching
+            /// end line are parsed as paragraphs, see
+            /// [`Org::parse_with_recovery`](crate::Org::parse_with_recovery).
+            pub fn block_name(&self) -> Token {
+                block_name(&self.syntax)
+            }
+        }
+    };
+}
+
+impl_block_name!(SourceBlock);
+impl_block_name!(ExportBlock);
+impl_block_name!(CenterBlock);
+impl_block_name!(CommentBlock);
+impl_block_name!(ExampleBlock);
+impl_block_name!(QuoteBlock);
+impl_block_name!(VerseBlock);
+
+impl SpecialBlock {
+    /// Returns the type of this block, like `note` in `#+BEGIN_note`
+    ///
+    /// Any name other than those of lesser and greater blocks, like `SRC`
+    /// or `QUOTE`, makes a special block. It's kept in its original case.
+    /// The block ends at the first `#+END_` line of the same type, compared
+    /// case-insensitively.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::SpecialBlock};
+    ///
+    /// let org = Org::parse("#+begin_my-note\n#+end_my\ntext\n#+END_MY-NOTE");
+    /// let block = org.first_node::<SpecialBlock>().unwrap();
+    /// assert_eq!(block.block_name(), "my-note");
+    ///
+    /// let org = Org::parse("#+BEGIN_note\ntext\n#+END_warning");
+    /// assert!(org.first_node::<SpecialBlock>().is_none());
+    /// ```
+    pub fn block_name(&self) -> Token {
+        block_name(&self.syntax)
+    }
+}
+
+fn block_name(syntax: &SyntaxNode) -> Token {
+    syntax
+        .children()
+        .find(|n| n.kind() == SyntaxKind::BLOCK_BEGIN)
+        .and_then(|n| {
+            n.children_with_tokens()
+                .filter_map(filter_token(SyntaxKind::TEXT))
+                .nth(1)
+        })
+        .expect("block must contain a name")
+}
@@ -1,8 +1,11 @@
-use rowan::ast::support;
+use std::collections::BTreeMap;
+use std::time::Duration;
 
-use crate::{ast::Token, SyntaxKind};
+use rowan::ast::{support, AstNode};
 
-use super::{Clock, Timestamp};
+use crate::{ast::Token, Org, SyntaxKind};
+
+use super::{timestamp::civil_from_days, Clock, Headline, Timestamp};
 
 impl Clock {
     pub fn value(&self) -> Option<Timestamp> {
@@ -16,7 +19,17 @@ impl Clock {
     /// assert!(clock.duration().is_none());
     /// let clock = Org::parse("CLOCK: [2003-09-16 Tue 09:39] =>12:00").first_node::<Clock>().unwrap();
     /// assert_eq!(clock.duration().unwrap(), "12:00");
+    /// ```
+    ///
+    /// Whitespace after `=>` isn't part of the duration:
     ///
+    /// ```rust
+    /// use orgize::{Org, ast::Clock};
+    ///
+    /// let clock = Org::parse("CLOCK: [2024-10-12 Sat 09:00]--[2024-10-12 Sat 10:23] =>  1:23")
+    ///     .first_node::<Clock>()
+    ///     .unwrap();
+    /// assert_eq!(clock.duration().unwrap(), "1:23");
     /// ```
     pub fn duration(&self) -> Option<Token> {
         self.syntax
@@ -38,6 +51,18 @@ impl Clock {
     /// let clock = Org::parse("CLOCK: [2003-09-16 Tue 09:39] =>12:00").first_node::<Clock>().unwrap();
     /// assert!(clock.is_closed());
     /// ```
+    ///
+    /// A clock is closed once Org-mode has written its `=>` duration, a
+    /// time range alone isn't enough:
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Clock};
+    ///
+    /// let clock = Org::parse("CLOCK: [2024-10-12 Sat 09:00]--[2024-10-12 Sat 10:23]")
+    ///     .first_node::<Clock>()
+    ///     .unwrap();
+    /// assert!(!clock.is_closed());
+    /// ```
     pub fn is_closed(&self) -> bool {
         self.syntax
             .children_with_tokens()
@@ -56,3 +81,113 @@ impl Clock {
         !self.is_closed()
     }
 }
+
+/// How [`Org::clock_report`] groups clocked time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockGrouping {
+    /// By date, formatted as `YYYY-MM-DD`
+    ///
+    /// Clocks spanning midnight are split across days.
+    Day,
+    /// By tag, including inherited tags
+    ///
+    /// A clock counts toward every tag of its headline. Clocks in untagged
+    /// headlines are not reported.
+    Tag,
+    /// By the title of the top-level headline containing the clock
+    ///
+    /// Clocks outside any headline are not reported.
+    TopHeadline,
+}
+
+impl Org {
+    /// Returns total clocked time in this document, grouped by day, tag or
+    /// top-level headline
+    ///
+    /// Every closed clock line is counted, wherever it appears. Durations
+    /// are computed from the clock timestamps rather than the `=>` total,
+    /// and running clocks are skipped.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::ClockGrouping};
+    /// use std::time::Duration;
+    ///
+    /// let org = Org::parse(r#"#+FILETAGS: :work:
+    /// * Project :dev:
+    /// :LOGBOOK:
+    /// CLOCK: [2024-01-01 Mon 23:00]--[2024-01-02 Tue 01:30] =>  2:30
+    /// :END:
+    /// ** Review :review:
+    /// CLOCK: [2024-01-02 Tue 10:00]--[2024-01-02 Tue 11:00] =>  1:00
+    /// CLOCK: [2024-01-02 Tue 12:00]
+    /// * Admin
+    /// CLOCK: [2024-01-03 Wed 09:00]--[2024-01-03 Wed 09:15] =>  0:15
+    /// "#);
+    ///
+    /// let minutes = |m: u64| Duration::from_secs(m * 60);
+    ///
+    /// let report = org.clock_report(ClockGrouping::Day);
+    /// assert_eq!(report.len(), 3);
+    /// assert_eq!(report["2024-01-01"], minutes(60));
+    /// assert_eq!(report["2024-01-02"], minutes(90 + 60));
+    /// assert_eq!(report["2024-01-03"], minutes(15));
+    ///
+    /// let report = org.clock_report(ClockGrouping::Tag);
+    /// assert_eq!(report["work"], minutes(150 + 60 + 15));
+    /// assert_eq!(report["dev"], minutes(150 + 60));
+    /// assert_eq!(report["review"], minutes(60));
+    ///
+    /// let report = org.clock_report(ClockGrouping::TopHeadline);
+    /// assert_eq!(report["Project"], minutes(150 + 60));
+    /// assert_eq!(report["Admin"], minutes(15));
+    /// ```
+    pub fn clock_report(&self, grouping: ClockGrouping) -> BTreeMap<String, Duration> {
+        let mut report = BTreeMap::<String, Duration>::new();
+        let mut add = |key: String, minutes: i64| {
+            let duration = Duration::from_secs(minutes as u64 * 60);
+            *report.entry(key).or_default() += duration;
+        };
+
+        for clock in self.document().syntax.descendants().filter_map(Clock::cast) {
+            if !clock.is_closed() {
+                continue;
+            }
+            let Some(ts) = clock.value() else {
+                continue;
+            };
+            let (Some(start), Some(end)) = (ts.start_minutes(), ts.end_minutes()) else {
+                continue;
+            };
+            if end < start {
+                continue;
+            }
+            let headline = clock.syntax.ancestors().find_map(Headline::cast);
+
+            match grouping {
+                ClockGrouping::Day => {
+                    let mut current = start;
+                    while current < end {
+                        let day = current.div_euclid(24 * 60);
+                        let next = ((day + 1) * 24 * 60).min(end);
+                        let (year, month, day) = civil_from_days(day);
+                        add(format!("{year:04}-{month:02}-{day:02}"), next - current);
+                        current = next;
+                    }
+                }
+                ClockGrouping::Tag => {
+                    for tag in headline.iter().flat_map(|h| h.inherited_tags()) {
+                        add(tag, end - start);
+                    }
+                }
+                ClockGrouping::TopHeadline => {
+                    let top = clock.syntax.ancestors().filter_map(Headline::cast).last();
+                    if let Some(top) = top {
+                        add(top.title_raw().trim().to_string(), end - start);
+                    }
+                }
+            }
+        }
+
+        report
+    }
+}
@@ -0,0 +1,66 @@
+use crate::SyntaxKind;
+
+use super::{filter_token, Cookie};
+
+/// Value of a statistics cookie, returned by [`Cookie::value`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CookieValue {
+    /// `[done/total]`
+    ///
+    /// Both are zero for the empty form `[/]`.
+    Fraction { done: usize, total: usize },
+    /// `[percent%]`
+    ///
+    /// It's zero for the empty form `[%]`, and capped at 100.
+    Percent(u8),
+}
+
+impl Cookie {
+    /// Returns the value of this cookie
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{Cookie, CookieValue}};
+    ///
+    /// let cookie = Org::parse("- [1/3] a").first_node::<Cookie>().unwrap();
+    /// assert_eq!(cookie.value(), CookieValue::Fraction { done: 1, total: 3 });
+    /// let cookie = Org::parse("- [33%] a").first_node::<Cookie>().unwrap();
+    /// assert_eq!(cookie.value(), CookieValue::Percent(33));
+    ///
+    /// let cookie = Org::parse("- [/] a").first_node::<Cookie>().unwrap();
+    /// assert_eq!(cookie.value(), CookieValue::Fraction { done: 0, total: 0 });
+    /// let cookie = Org::parse("- [%] a").first_node::<Cookie>().unwrap();
+    /// assert_eq!(cookie.value(), CookieValue::Percent(0));
+    /// ```
+    pub fn value(&self) -> CookieValue {
+        let mut numbers = self
+            .syntax
+            .children_with_tokens()
+            .filter_map(filter_token(SyntaxKind::TEXT))
+            .map(|t| t.parse::<usize>().unwrap_or(0));
+
+        let first = numbers.next().unwrap_or(0);
+
+        if self.is_percent() {
+            CookieValue::Percent(first.min(100) as u8)
+        } else {
+            CookieValue::Fraction {
+                done: first,
+                total: numbers.next().unwrap_or(0),
+            }
+        }
+    }
+
+    /// Returns `true` if this cookie is a percentage, like `[50%]`
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Cookie};
+    ///
+    /// assert!(Org::parse("- [50%] a").first_node::<Cookie>().unwrap().is_percent());
+    /// assert!(!Org::parse("- [1/2] a").first_node::<Cookie>().unwrap().is_percent());
+    /// ```
+    pub fn is_percent(&self) -> bool {
+        self.syntax
+            .children_with_tokens()
+            .any(|e| e.kind() == SyntaxKind::PERCENT)
+    }
+}
@@ -1,8 +1,10 @@
+use std::collections::HashMap;
+
 use rowan::ast::AstNode;
 
 use crate::Org;
 
-use super::{Document, Keyword, PropertyDrawer};
+use super::{Document, Keyword, PropertyDrawer, StartupVisibility};
 
 impl Document {
     /// Returns an iterator of keywords in zeroth section
@@ -26,6 +28,27 @@ impl Document {
             .flat_map(|section| section.syntax.children().filter_map(Keyword::cast))
     }
 
+    /// Returns the value of first top-level keyword with given key
+    ///
+    /// Keys are compared case-insensitively, and the value is trimmed. Use
+    /// [`Document::keywords`] for keywords that can be repeated, like
+    /// `#+OPTIONS`.
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// let org = Org::parse("#+TITLE: My Doc\n#+options: toc:nil\n#+OPTIONS: num:nil\n* a");
+    /// let doc = org.document();
+    /// assert_eq!(doc.keyword("title").unwrap(), "My Doc");
+    /// assert_eq!(doc.keyword("OPTIONS").unwrap(), "toc:nil");
+    /// assert!(doc.keyword("AUTHOR").is_none());
+    /// ```
+    pub fn keyword(&self, key: &str) -> Option<String> {
+        self.keywords()
+            .find(|kw| kw.key().eq_ignore_ascii_case(key))
+            .map(|kw| kw.value().trim().to_string())
+    }
+
     /// Returns the value in top-level `#+TITLE`
     ///
     /// Multiple `#+TITLE` are joined with spaces.
@@ -56,6 +79,243 @@ impl Document {
             })
     }
 
+    /// Returns tags declared in top-level `#+FILETAGS`
+    ///
+    /// Tags from multiple `#+FILETAGS` are merged, and duplicated tags are
+    /// only returned once.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Document};
+    ///
+    /// let org = Org::parse("#+FILETAGS: :a:b:\n#+filetags: :b:c:\n* headline");
+    /// let doc = org.first_node::<Document>().unwrap();
+    /// assert_eq!(doc.filetags(), vec!["a", "b", "c"]);
+    ///
+    /// let org = Org::parse("#+TITLE: hello");
+    /// assert!(org.document().filetags().is_empty());
+    /// ```
+    pub fn filetags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = vec![];
+        for keyword in self
+            .keywords()
+            .filter(|kw| kw.key().eq_ignore_ascii_case("FILETAGS"))
+        {
+            for tag in keyword
+                .value()
+                .split(|c: char| c == ':' || c.is_whitespace())
+                .filter(|t| !t.is_empty())
+            {
+                if !tags.iter().any(|t| t == tag) {
+                    tags.push(tag.to_string());
+                }
+            }
+        }
+        tags
+    }
+
+    /// Returns options declared in top-level `#+STARTUP`
+    ///
+    /// Options from multiple `#+STARTUP` are concatenated in order.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Document};
+    ///
+    /// let org = Org::parse("#+STARTUP: overview indent\n#+startup: odd");
+    /// let doc = org.first_node::<Document>().unwrap();
+    /// assert_eq!(doc.startup_options(), vec!["overview", "indent", "odd"]);
+    /// ```
+    pub fn startup_options(&self) -> Vec<String> {
+        self.keywords()
+            .filter(|kw| kw.key().eq_ignore_ascii_case("STARTUP"))
+            .flat_map(|kw| {
+                kw.value()
+                    .split_whitespace()
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Returns todo keywords declared in top-level `#+TODO`, `#+SEQ_TODO`
+    /// and `#+TYP_TODO`, as active and done states
+    ///
+    /// States after `|` are done states. Without `|`, the last state is
+    /// the done state. Fast access keys like `(w@/!)` are dropped.
+    ///
+    /// These keywords are recognized by [`ParseConfig::parse`](crate::ParseConfig::parse)
+    /// in addition to [`ParseConfig::todo_keywords`](crate::ParseConfig::todo_keywords).
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Document};
+    ///
+    /// let org = Org::parse("#+TODO: TODO(t) NEXT | DONE CANCELLED(c@)\n#+SEQ_TODO: REPORT BUG FIXED");
+    /// assert_eq!(
+    ///     org.document().todo_keywords(),
+    ///     (
+    ///         vec!["TODO".to_string(), "NEXT".into(), "REPORT".into(), "BUG".into()],
+    ///         vec!["DONE".to_string(), "CANCELLED".into(), "FIXED".into()]
+    ///     )
+    /// );
+    /// ```
+    pub fn todo_keywords(&self) -> (Vec<String>, Vec<String>) {
+        let mut todo: Vec<String> = vec![];
+        let mut done: Vec<String> = vec![];
+        for keyword in self.keywords().filter(|kw| {
+            let key = kw.key();
+            key.eq_ignore_ascii_case("TODO")
+                || key.eq_ignore_ascii_case("SEQ_TODO")
+                || key.eq_ignore_ascii_case("TYP_TODO")
+        }) {
+            let value = keyword.value();
+            let states = value
+                .split_whitespace()
+                .map(|s| s.split('(').next().unwrap_or(s))
+                .collect::<Vec<_>>();
+
+            let (active, finished) = match states.iter().position(|&s| s == "|") {
+                Some(idx) => (&states[..idx], &states[idx + 1..]),
+                None if states.is_empty() => continue,
+                None => states.split_at(states.len() - 1),
+            };
+
+            for (states, list) in [(active, &mut todo), (finished, &mut done)] {
+                for state in states.iter().filter(|s| !s.is_empty() && **s != "|") {
+                    if !list.iter().any(|s| s == state) {
+                        list.push(state.to_string());
+                    }
+                }
+            }
+        }
+        (todo, done)
+    }
+
+    /// Returns initial visibility declared in top-level `#+STARTUP`
+    ///
+    /// When more than one visibility option is given, the last one wins.
+    /// Defaults to [`StartupVisibility::ShowEverything`].
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::StartupVisibility};
+    ///
+    /// let org = Org::parse("#+STARTUP: overview indent\n#+STARTUP: show3levels");
+    /// assert_eq!(org.document().startup_visibility(), StartupVisibility::ShowLevels(3));
+    ///
+    /// let org = Org::parse("#+STARTUP: indent");
+    /// assert_eq!(org.document().startup_visibility(), StartupVisibility::ShowEverything);
+    /// ```
+    pub fn startup_visibility(&self) -> StartupVisibility {
+        self.startup_options()
+            .iter()
+            .rev()
+            .find_map(|option| StartupVisibility::from_option(option))
+            .unwrap_or_default()
+    }
+
+    /// Returns link abbreviations declared in top-level `#+LINK`
+    ///
+    /// Keys are abbreviations and values are replacement texts. When an
+    /// abbreviation is declared more than once, the last one wins.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Document};
+    ///
+    /// let org = Org::parse("#+LINK: gh https://github.com/%s\n#+LINK: wiki https://en.wikipedia.org/wiki/");
+    /// let abbrevs = org.document().link_abbreviations();
+    /// assert_eq!(abbrevs.len(), 2);
+    /// assert_eq!(abbrevs["gh"], "https://github.com/%s");
+    /// assert_eq!(abbrevs["wiki"], "https://en.wikipedia.org/wiki/");
+    /// ```
+    pub fn link_abbreviations(&self) -> HashMap<String, String> {
+        self.keywords()
+            .filter(|kw| kw.key().eq_ignore_ascii_case("LINK"))
+            .filter_map(|kw| {
+                let value = kw.value();
+                let (abbrev, replacement) = value.trim().split_once(char::is_whitespace)?;
+                Some((abbrev.to_string(), replacement.trim().to_string()))
+            })
+            .collect()
+    }
+
+    /// Returns variable bindings declared in `#+BIND`, in document order
+    ///
+    /// Like Emacs, bindings anywhere in the document are collected, not
+    /// only top-level ones. Values are Lisp expressions, returned as is and
+    /// never evaluated.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Document};
+    ///
+    /// let org = Org::parse(r#"#+BIND: org-export-with-toc nil
+    /// * a
+    /// #+bind: my-var "some value"
+    /// #+BIND: invalid"#);
+    /// assert_eq!(
+    ///     org.document().bindings(),
+    ///     vec![
+    ///         ("org-export-with-toc".to_string(), "nil".to_string()),
+    ///         ("my-var".to_string(), "\"some value\"".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn bindings(&self) -> Vec<(String, String)> {
+        self.syntax
+            .descendants()
+            .filter_map(Keyword::cast)
+            .filter(|kw| kw.key().eq_ignore_ascii_case("BIND"))
+            .filter_map(|kw| {
+                let value = kw.value();
+                let (name, value) = value.trim().split_once(char::is_whitespace)?;
+                Some((name.to_string(), value.trim().to_string()))
+            })
+            .collect()
+    }
+
+    /// Returns properties declared in top-level `#+PROPERTY`
+    ///
+    /// A key ending with `+`, like `#+PROPERTY: var+ x`, appends its value
+    /// to the existing one, separated by a space.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Document};
+    ///
+    /// let org = Org::parse("#+PROPERTY: Effort_ALL 0 0:30 1:00\n#+PROPERTY: var x=1\n#+PROPERTY: var+ y=2");
+    /// let properties = org.document().file_properties();
+    /// assert_eq!(properties.len(), 2);
+    /// assert_eq!(properties["Effort_ALL"], "0 0:30 1:00");
+    /// assert_eq!(properties["var"], "x=1 y=2");
+    /// ```
+    pub fn file_properties(&self) -> HashMap<String, String> {
+        let mut properties: HashMap<String, String> = HashMap::new();
+        for keyword in self
+            .keywords()
+            .filter(|kw| kw.key().eq_ignore_ascii_case("PROPERTY"))
+        {
+            let value = keyword.value();
+            let value = value.trim();
+            let (key, value) = value
+                .split_once(char::is_whitespace)
+                .map_or((value, ""), |(k, v)| (k, v.trim()));
+
+            if let Some(key) = key.strip_suffix('+') {
+                if let Some(existing) = properties
+                    .iter_mut()
+                    .find_map(|(k, v)| k.eq_ignore_ascii_case(key).then_some(v))
+                {
+                    if !existing.is_empty() {
+                        existing.push(' ');
+                    }
+                    existing.push_str(value);
+                    continue;
+                }
+                properties.insert(key.to_string(), value.to_string());
+            } else if !key.is_empty() {
+                properties.retain(|k, _| !k.eq_ignore_ascii_case(key));
+                properties.insert(key.to_string(), value.to_string());
+            }
+        }
+        properties
+    }
+
     /// Returns top-level properties drawer
     ///
     /// ```rust
@@ -81,6 +341,67 @@ impl Org {
         self.document().title()
     }
 
+    /// Equals to `self.document().filetags()`, see [Document::filetags]
+    pub fn filetags(&self) -> Vec<String> {
+        self.document().filetags()
+    }
+
+    /// Equals to `self.document().startup_options()`, see [Document::startup_options]
+    pub fn startup_options(&self) -> Vec<String> {
+        self.document().startup_options()
+    }
+
+    /// Equals to `self.document().todo_keywords()`, see [Document::todo_keywords]
+    pub fn todo_keywords(&self) -> (Vec<String>, Vec<String>) {
+        self.document().todo_keywords()
+    }
+
+    /// Equals to `self.document().startup_visibility()`, see [Document::startup_visibility]
+    pub fn startup_visibility(&self) -> StartupVisibility {
+        self.document().startup_visibility()
+    }
+
+    /// Equals to `self.document().link_abbreviations()`, see [Document::link_abbreviations]
+    pub fn link_abbreviations(&self) -> HashMap<String, String> {
+        self.document().link_abbreviations()
+    }
+
+    /// Equals to `self.document().bindings()`, see [Document::bindings]
+    pub fn bindings(&self) -> Vec<(String, String)> {
+        self.document().bindings()
+    }
+
+    /// Equals to `self.document().file_properties()`, see [Document::file_properties]
+    pub fn file_properties(&self) -> HashMap<String, String> {
+        self.document().file_properties()
+    }
+
+    /// Returns the top-level property drawer, which precedes the first
+    /// headline
+    ///
+    /// Equals to `self.document().properties()`, see [Document::properties].
+    /// Its inheritable properties are inherited by all headlines, see
+    /// [`Headline::inherited_property_with`](crate::ast::Headline::inherited_property_with).
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// let org = Org::parse(":PROPERTIES:\n:CATEGORY: notes\n:END:\n* a");
+    /// let drawer = org.document_properties().unwrap();
+    /// assert_eq!(drawer.get("CATEGORY").unwrap(), "notes");
+    ///
+    /// let org = Org::parse("* a\n:PROPERTIES:\n:CATEGORY: notes\n:END:");
+    /// assert!(org.document_properties().is_none());
+    /// ```
+    pub fn document_properties(&self) -> Option<PropertyDrawer> {
+        self.document().properties()
+    }
+
+    /// Equals to `self.document().keyword(key)`, see [Document::keyword]
+    pub fn keyword(&self, key: &str) -> Option<String> {
+        self.document().keyword(key)
+    }
+
     /// Equals to `self.document().keywords()`, see [Document::keywords]
     pub fn keywords(&self) -> impl Iterator<Item = Keyword> {
         self.document().keywords()
@@ -21,6 +21,9 @@ impl Entity {
     /// assert_eq!(e.name(), "alpha");
     /// let e = Org::parse("\\_     ").first_node::<Entity>().unwrap();
     /// assert_eq!(e.name(), "     ");
+    ///
+    /// // unknown entities are kept as plain text
+    /// assert!(Org::parse("\\notanentity").first_node::<Entity>().is_none());
     /// ```
     pub fn name(&self) -> &str {
         self.entity().map_or_else(
@@ -77,6 +80,9 @@ impl Entity {
     ///
     /// let e = Org::parse("\\S").first_node::<Entity>().unwrap();
     /// assert_eq!(e.html(), "&sect;");
+    /// let e = Org::parse("a\\nbsp{}b").first_node::<Entity>().unwrap();
+    /// assert_eq!(e.html(), "&nbsp;");
+    /// assert_eq!(Org::parse("a\\nbsp{}b").to_html(), "<main><section><p>a&nbsp;b</p></section></main>");
     /// ```
     pub fn html(&self) -> &str {
         self.entity().map_or_else(
@@ -135,6 +141,10 @@ impl Entity {
     /// assert_eq!(e.utf8(), "§");
     /// let e = Org::parse("\\rsaquo").first_node::<Entity>().unwrap();
     /// assert_eq!(e.utf8(), "›");
+    /// let e = Org::parse("\\alpha").first_node::<Entity>().unwrap();
+    /// assert_eq!(e.utf8(), "α");
+    /// let e = Org::parse("\\nbsp").first_node::<Entity>().unwrap();
+    /// assert_eq!(e.utf8(), "\u{a0}");
     /// ```
     pub fn utf8(&self) -> &str {
         self.entity().map_or_else(
@@ -0,0 +1,130 @@
+use rowan::{TextRange, TextSize};
+
+use super::Headline;
+
+/// Folding information of a headline, returned by [`Headline::fold_summary`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoldSummary {
+    /// Range to collapse when the headline is folded
+    ///
+    /// It starts at the end of the title line, before the line ending, and
+    /// ends at the end of the last non-blank line of the subtree, so
+    /// trailing blank lines stay visible.
+    pub range: TextRange,
+
+    /// Range to collapse when only child headlines are shown, i.e. the
+    /// contents view
+    ///
+    /// It starts at the end of the title line and ends at the end of the
+    /// last non-blank line before the first child headline. Equals to
+    /// `range` if there's no child headline.
+    pub body_range: TextRange,
+
+    /// `true` if the headline has child headlines
+    pub has_children: bool,
+
+    /// `true` if the headline has anything besides blank lines between the
+    /// title line and the first child headline, like planning, property
+    /// drawer or section
+    pub has_body: bool,
+}
+
+impl FoldSummary {
+    /// Returns `true` if the headline has anything to fold
+    pub fn is_foldable(&self) -> bool {
+        self.has_children || self.has_body
+    }
+}
+
+/// Initial visibility declared in `#+STARTUP`, returned by
+/// [`Document::startup_visibility`](super::Document::startup_visibility)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StartupVisibility {
+    /// `overview` or `fold`: only top-level headlines are shown
+    Overview,
+    /// `content`: all headlines are shown, but not their bodies
+    Content,
+    /// `showall` or `nofold`: everything is shown except drawers
+    ShowAll,
+    /// `show2levels` to `show5levels`: headlines up to given level are shown
+    ShowLevels(usize),
+    /// `showeverything`: everything is shown, including drawers
+    ///
+    /// It's the default when `#+STARTUP` doesn't set the visibility.
+    #[default]
+    ShowEverything,
+}
+
+impl StartupVisibility {
+    pub(crate) fn from_option(option: &str) -> Option<Self> {
+        match option {
+            "overview" | "fold" => Some(StartupVisibility::Overview),
+            "content" => Some(StartupVisibility::Content),
+            "showall" | "nofold" => Some(StartupVisibility::ShowAll),
+            "show2levels" => Some(StartupVisibility::ShowLevels(2)),
+            "show3levels" => Some(StartupVisibility::ShowLevels(3)),
+            "show4levels" => Some(StartupVisibility::ShowLevels(4)),
+            "show5levels" => Some(StartupVisibility::ShowLevels(5)),
+            "showeverything" => Some(StartupVisibility::ShowEverything),
+            _ => None,
+        }
+    }
+}
+
+impl Headline {
+    /// Returns folding information of this headline, for implementing
+    /// visibility cycling in editors
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline, rowan::ast::AstNode};
+    ///
+    /// let org = Org::parse("* a\nSCHEDULED: <2024-01-01 Mon>\n** b\ntext\n\n* c\n\n* d");
+    /// let hdls = org.document().syntax().descendants().filter_map(Headline::cast).collect::<Vec<_>>();
+    /// let text = org.to_org();
+    ///
+    /// let a = hdls[0].fold_summary();
+    /// assert!(a.has_children && a.has_body && a.is_foldable());
+    /// assert_eq!(&text[a.range], "\nSCHEDULED: <2024-01-01 Mon>\n** b\ntext");
+    /// assert_eq!(&text[a.body_range], "\nSCHEDULED: <2024-01-01 Mon>");
+    ///
+    /// let b = hdls[1].fold_summary();
+    /// assert!(!b.has_children && b.has_body);
+    /// assert_eq!(&text[b.range], "\ntext");
+    /// assert_eq!(b.range, b.body_range);
+    ///
+    /// // blank lines only
+    /// assert!(!hdls[2].fold_summary().is_foldable());
+    /// assert!(!hdls[3].fold_summary().is_foldable());
+    /// assert!(hdls[3].fold_summary().range.is_empty());
+    /// ```
+    pub fn fold_summary(&self) -> FoldSummary {
+        let text = self.syntax.to_string();
+        let start = self.start();
+
+        let title_end = text.find(['\r', '\n']).unwrap_or(text.len());
+        let subtree_end = text.trim_end().len().max(title_end);
+
+        let first_child = self.headlines().next();
+        let body_end = match &first_child {
+            Some(child) => {
+                let offset = usize::from(child.start() - start);
+                text[..offset].trim_end().len().max(title_end)
+            }
+            None => subtree_end,
+        };
+
+        let range = |end: usize| {
+            TextRange::new(
+                start + TextSize::from(title_end as u32),
+                start + TextSize::from(end as u32),
+            )
+        };
+
+        FoldSummary {
+            range: range(subtree_end),
+            body_range: range(body_end),
+            has_children: first_child.is_some(),
+            has_body: !text[title_end..body_end].trim().is_empty(),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use rowan::{ast::AstNode, NodeOrToken};
+
+use super::{filter_token, FnDef, FnRef, Token};
+use crate::{syntax::SyntaxKind, Org};
+
+impl FnRef {
+    /// Returns the label of this footnote reference, or `None` if it's
+    /// an anonymous inline footnote
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::FnRef};
+    ///
+    /// let fn_ref = Org::parse("a[fn:1]").first_node::<FnRef>().unwrap();
+    /// assert_eq!(fn_ref.label().unwrap(), "1");
+    /// let fn_ref = Org::parse("a[fn:note:inline]").first_node::<FnRef>().unwrap();
+    /// assert_eq!(fn_ref.label().unwrap(), "note");
+    /// let fn_ref = Org::parse("a[fn::inline]").first_node::<FnRef>().unwrap();
+    /// assert!(fn_ref.label().is_none());
+    /// ```
+    pub fn label(&self) -> Option<Token> {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(filter_token(SyntaxKind::TEXT))
+            .nth(1)
+            .filter(|t| !t.is_empty())
+    }
+
+    /// Returns the raw definition of this footnote reference, if it's an
+    /// inline footnote
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::FnRef};
+    ///
+    /// let fn_ref = Org::parse("a[fn::an *inline* note]").first_node::<FnRef>().unwrap();
+    /// assert!(fn_ref.label().is_none());
+    /// assert_eq!(fn_ref.definition().unwrap(), "an *inline* note");
+    ///
+    /// let fn_ref = Org::parse("a[fn:1]").first_node::<FnRef>().unwrap();
+    /// assert!(fn_ref.definition().is_none());
+    /// ```
+    pub fn definition(&self) -> Option<String> {
+        let mut children = self
+            .syntax
+            .children_with_tokens()
+            .skip_while(|e| e.kind() != SyntaxKind::COLON)
+            .skip(1)
+            .skip_while(|e| e.kind() != SyntaxKind::COLON)
+            .skip(1)
+            .peekable();
+
+        children.peek()?;
+
+        Some(
+            children
+                .filter(|e| e.kind() != SyntaxKind::R_BRACKET)
+                .map(|e| match e {
+                    NodeOrToken::Node(n) => n.to_string(),
+                    NodeOrToken::Token(t) => t.text().to_string(),
+                })
+                .collect(),
+        )
+    }
+}
+
+impl FnDef {
+    /// Returns the label of this footnote definition
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::FnDef};
+    ///
+    /// let fn_def = Org::parse("[fn:WORD-1] https://orgmode.org").first_node::<FnDef>().unwrap();
+    /// assert_eq!(fn_def.label(), "WORD-1");
+    /// ```
+    pub fn label(&self) -> Token {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(filter_token(SyntaxKind::TEXT))
+            .nth(1)
+            .expect("fn def must contains label")
+    }
+
+    /// Returns the content of this footnote definition, without leading
+    /// and trailing whitespace
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::FnDef};
+    ///
+    /// let fn_def = Org::parse("[fn:1] The Org homepage\n").first_node::<FnDef>().unwrap();
+    /// assert_eq!(fn_def.content(), "The Org homepage");
+    /// let fn_def = Org::parse("[fn:1] [[https://orgmode.org][Org]] homepage\n").first_node::<FnDef>().unwrap();
+    /// assert_eq!(fn_def.content(), "[[https://orgmode.org][Org]] homepage");
+    /// let fn_def = Org::parse("[fn:1]").first_node::<FnDef>().unwrap();
+    /// assert_eq!(fn_def.content(), "");
+    /// ```
+    pub fn content(&self) -> String {
+        self.syntax
+            .children_with_tokens()
+            .skip_while(|e| e.kind() != SyntaxKind::R_BRACKET)
+            .skip(1)
+            .take_while(|e| !matches!(e.kind(), SyntaxKind::NEW_LINE | SyntaxKind::BLANK_LINE))
+            .map(|e| e.to_string())
+            .collect::<String>()
+            .trim()
+            .to_string()
+    }
+}
+
+impl Org {
+    /// Returns the footnote definition with given label
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::FnRef};
+    ///
+    /// let org = Org::parse("See the manual[fn:manual].\n\n[fn:manual] https://orgmode.org/manual\n");
+    /// let fn_ref = org.first_node::<FnRef>().unwrap();
+    /// let fn_def = org.footnote_definition(&fn_ref.label().unwrap()).unwrap();
+    /// assert_eq!(fn_def.content(), "https://orgmode.org/manual");
+    ///
+    /// assert!(org.footnote_definition("missing").is_none());
+    /// ```
+    pub fn footnote_definition(&self, label: &str) -> Option<FnDef> {
+        self.document()
+            .syntax()
+            .descendants()
+            .filter_map(FnDef::cast)
+            .find(|fn_def| fn_def.label() == label)
+    }
+}
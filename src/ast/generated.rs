@@ -143,23 +143,24 @@ impl Paragraph {
         super::blank_lines(&self.syntax)
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -359,23 +360,24 @@ impl OrgTable {
         super::blank_lines(&self.syntax)
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -487,23 +489,24 @@ impl List {
         support::children(&self.syntax)
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -612,23 +615,24 @@ impl DynBlock {
         self.syntax.to_string()
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -851,23 +855,24 @@ impl FnDef {
         super::blank_lines(&self.syntax)
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -912,23 +917,24 @@ impl Comment {
         super::blank_lines(&self.syntax)
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -1011,23 +1017,24 @@ impl FixedWidth {
         super::blank_lines(&self.syntax)
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -1066,23 +1073,24 @@ impl SpecialBlock {
         self.syntax.to_string()
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -1121,23 +1129,24 @@ impl QuoteBlock {
         self.syntax.to_string()
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -1176,23 +1185,24 @@ impl CenterBlock {
         self.syntax.to_string()
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -1231,23 +1241,24 @@ impl VerseBlock {
         self.syntax.to_string()
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -1286,23 +1297,24 @@ impl CommentBlock {
         self.syntax.to_string()
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -1341,23 +1353,24 @@ impl ExampleBlock {
         self.syntax.to_string()
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -1396,23 +1409,24 @@ impl ExportBlock {
         self.syntax.to_string()
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -1451,23 +1465,24 @@ impl SourceBlock {
         self.syntax.to_string()
     }
     pub fn caption(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "CAPTION")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("CAPTION"))
     }
     pub fn header(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "HEADER")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("HEADER"))
     }
     pub fn name(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "NAME")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("NAME"))
     }
     pub fn plot(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "PLOT")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("PLOT"))
     }
     pub fn results(&self) -> Option<AffiliatedKeyword> {
-        affiliated_keyword(&self.syntax, |k| k == "RESULTS")
+        affiliated_keyword(&self.syntax, |k| k.eq_ignore_ascii_case("RESULTS"))
     }
     pub fn attr(&self, backend: &str) -> Option<AffiliatedKeyword> {
         affiliated_keyword(&self.syntax, |k| {
-            k.starts_with("ATTR_") && &k[5..] == backend
+            k.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("ATTR_"))
+                && k[5..].eq_ignore_ascii_case(backend)
         })
     }
 }
@@ -1,8 +1,16 @@
-use rowan::{ast::AstNode, NodeOrToken};
+use rowan::{ast::AstNode, GreenNode, Language, NodeOrToken, TextRange, TextSize};
+use std::borrow::Cow;
 
-use crate::{syntax::SyntaxKind, SyntaxElement};
+use crate::{
+    escape,
+    syntax::{OrgLanguage, SyntaxKind},
+    width::display_width,
+    Org, ParseConfig, SyntaxElement,
+};
 
-use super::{filter_token, Clock, Drawer, Headline, Section, Timestamp, Token};
+use super::{
+    filter_token, Clock, Cookie, Document, Drawer, Headline, Keyword, Section, Timestamp, Token,
+};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TodoType {
@@ -10,6 +18,54 @@ pub enum TodoType {
     Done,
 }
 
+/// Controls which properties are looked up in ancestor headlines and at
+/// document level, see [`Headline::inherited_property_with`]
+///
+/// Equivalent to `org-use-property-inheritance`. `CATEGORY`, `COLUMNS`,
+/// `ARCHIVE`, `LOGGING` and properties ending with `_ALL` are always
+/// inherited.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum PropertyInheritance {
+    /// Only inherit properties that are always inherited, equivalent to `nil`
+    #[default]
+    None,
+    /// Inherit every property, equivalent to `t`
+    All,
+    /// Also inherit listed properties, compared case-insensitively
+    Only(Vec<String>),
+}
+
+impl PropertyInheritance {
+    /// Returns true if given property is inherited
+    ///
+    /// ```rust
+    /// use orgize::ast::PropertyInheritance;
+    ///
+    /// assert!(PropertyInheritance::None.is_inherited("category"));
+    /// assert!(PropertyInheritance::None.is_inherited("Effort_ALL"));
+    /// assert!(!PropertyInheritance::None.is_inherited("OWNER"));
+    /// assert!(PropertyInheritance::All.is_inherited("OWNER"));
+    /// assert!(PropertyInheritance::Only(vec!["owner".into()]).is_inherited("OWNER"));
+    /// ```
+    pub fn is_inherited(&self, key: &str) -> bool {
+        let always = ["CATEGORY", "COLUMNS", "ARCHIVE", "LOGGING"]
+            .iter()
+            .any(|k| k.eq_ignore_ascii_case(key))
+            || key
+                .len()
+                .checked_sub(4)
+                .and_then(|i| key.get(i..))
+                .is_some_and(|suffix| suffix.eq_ignore_ascii_case("_ALL"));
+
+        always
+            || match self {
+                PropertyInheritance::None => false,
+                PropertyInheritance::All => true,
+                PropertyInheritance::Only(keys) => keys.iter().any(|k| k.eq_ignore_ascii_case(key)),
+            }
+    }
+}
+
 impl Headline {
     /// Return level of this headline
     ///
@@ -62,6 +118,26 @@ impl Headline {
     /// let hdl = Org::parse("*** DONE a").first_node::<Headline>().unwrap();
     /// assert_eq!(hdl.todo_type().unwrap(), TodoType::Done);
     /// ```
+    ///
+    /// Keywords besides `TODO` and `DONE` must be declared in
+    /// [`ParseConfig::todo_keywords`](crate::ParseConfig::todo_keywords):
+    ///
+    /// ```rust
+    /// use orgize::{Org, ParseConfig, ast::{Headline, TodoType}};
+    ///
+    /// let config = ParseConfig {
+    ///     todo_keywords: (vec!["NEXT".into(), "WAITING".into()], vec!["CANCELLED".into()]),
+    ///     ..Default::default()
+    /// };
+    /// let org = Org::parse_with_config("* WAITING foo", &config);
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.todo_type(), Some(TodoType::Todo));
+    /// assert_eq!(hdl.title_raw(), "foo");
+    ///
+    /// let hdl = Org::parse("* WAITING foo").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.todo_type(), None);
+    /// assert_eq!(hdl.title_raw(), "WAITING foo");
+    /// ```
     pub fn todo_type(&self) -> Option<TodoType> {
         self.syntax
             .children_with_tokens()
@@ -100,6 +176,82 @@ impl Headline {
         matches!(self.todo_type(), Some(TodoType::Done))
     }
 
+    /// Returns the range of leading stars
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline, TextRange};
+    ///
+    /// let hdl = Org::parse("** TODO [#A] title :a:b:").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.stars_range(), Some(TextRange::new(0.into(), 2.into())));
+    /// assert_eq!(hdl.todo_range(), Some(TextRange::new(3.into(), 7.into())));
+    /// assert_eq!(hdl.priority_range(), Some(TextRange::new(8.into(), 12.into())));
+    /// assert_eq!(hdl.tags_range(), Some(TextRange::new(19.into(), 24.into())));
+    ///
+    /// let hdl = Org::parse("* title").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.stars_range(), Some(TextRange::new(0.into(), 1.into())));
+    /// assert_eq!(hdl.todo_range(), None);
+    /// assert_eq!(hdl.priority_range(), None);
+    /// assert_eq!(hdl.tags_range(), None);
+    /// ```
+    pub fn stars_range(&self) -> Option<TextRange> {
+        self.syntax
+            .children_with_tokens()
+            .find_map(filter_token(SyntaxKind::HEADLINE_STARS))
+            .map(|stars| stars.text_range())
+    }
+
+    /// Returns the range of todo keyword, see [`Headline::stars_range`]
+    pub fn todo_range(&self) -> Option<TextRange> {
+        self.todo_keyword().map(|keyword| keyword.text_range())
+    }
+
+    /// Returns the range of priority cookie, including brackets,
+    /// see [`Headline::stars_range`]
+    pub fn priority_range(&self) -> Option<TextRange> {
+        self.syntax
+            .children()
+            .find(|n| n.kind() == SyntaxKind::HEADLINE_PRIORITY)
+            .map(|n| n.text_range())
+    }
+
+    /// Returns the range of tags, including colons,
+    /// see [`Headline::stars_range`]
+    pub fn tags_range(&self) -> Option<TextRange> {
+        self.syntax
+            .children()
+            .find(|n| n.kind() == SyntaxKind::HEADLINE_TAGS)
+            .map(|n| n.text_range())
+    }
+
+    /// Returns `true` if this headline has body text
+    ///
+    /// Blank lines and `LOGBOOK` drawers are not considered content, nor
+    /// are planning lines, property drawers and child headlines.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let hdl = Org::parse("* a\n\n** b\ntext").first_node::<Headline>().unwrap();
+    /// assert!(!hdl.has_content());
+    ///
+    /// let hdl = Org::parse(r#"* a
+    /// SCHEDULED: <2024-01-01 Mon>
+    /// :PROPERTIES:
+    /// :ID: a
+    /// :END:
+    /// :LOGBOOK:
+    /// CLOCK: [2024-01-01 Mon 10:00]--[2024-01-01 Mon 11:00] =>  1:00
+    /// :END:
+    /// "#).first_node::<Headline>().unwrap();
+    /// assert!(!hdl.has_content());
+    ///
+    /// let hdl = Org::parse("* a\n:LOGBOOK:\n:END:\ntext").first_node::<Headline>().unwrap();
+    /// assert!(hdl.has_content());
+    /// ```
+    pub fn has_content(&self) -> bool {
+        self.section().is_some_and(|section| section.has_content())
+    }
+
     /// Returns parsed title
     ///
     /// ```rust
@@ -120,6 +272,44 @@ impl Headline {
             .flat_map(|n| n.children_with_tokens())
     }
 
+    /// Returns the headline this headline is nested in
+    ///
+    /// Returns `None` for top-level headlines.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let org = Org::parse("* a\n*** b");
+    /// let a = org.first_node::<Headline>().unwrap();
+    /// assert!(a.parent_headline().is_none());
+    /// let b = a.headlines().next().unwrap();
+    /// assert_eq!(b.parent_headline(), Some(a));
+    /// ```
+    pub fn parent_headline(&self) -> Option<Headline> {
+        self.syntax.parent().and_then(Headline::cast)
+    }
+
+    /// Returns the first statistics cookie in title
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{CookieValue, Headline}};
+    ///
+    /// let hdl = Org::parse("* Project [1/3]").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.cookie().unwrap().value(), CookieValue::Fraction { done: 1, total: 3 });
+    ///
+    /// let hdl = Org::parse("* TODO [50%] Project :work:").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.cookie().unwrap().value(), CookieValue::Percent(50));
+    ///
+    /// let hdl = Org::parse("* Project\n- [1/3]").first_node::<Headline>().unwrap();
+    /// assert!(hdl.cookie().is_none());
+    /// ```
+    pub fn cookie(&self) -> Option<Cookie> {
+        self.title()
+            .filter_map(|e| e.into_node())
+            .flat_map(|n| n.descendants())
+            .find_map(Cookie::cast)
+    }
+
     /// Returns title raw string
     ///
     /// ```rust
@@ -137,6 +327,34 @@ impl Headline {
             .unwrap_or_default()
     }
 
+    /// Returns raw text of the section directly under this headline
+    ///
+    /// Planning, property drawer and sub-headlines aren't included. Returns
+    /// an empty string if this headline has no section, see
+    /// [`Headline::section`].
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let org = Org::parse("* a\nbody\n** b\nmore");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.content_raw(), "body\n");
+    ///
+    /// let org = Org::parse("* a\nSCHEDULED: <2024-01-01 Mon>\n:PROPERTIES:\n:ID: 1\n:END:\nbody");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.content_raw(), "body");
+    ///
+    /// let org = Org::parse("* a\n** b\nmore");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert!(hdl.section().is_none());
+    /// assert_eq!(hdl.content_raw(), "");
+    /// ```
+    pub fn content_raw(&self) -> String {
+        self.section()
+            .map(|section| section.raw())
+            .unwrap_or_default()
+    }
+
     /// Return `true` if this headline contains a COMMENT keyword
     ///      
     /// ```rust
@@ -148,6 +366,28 @@ impl Headline {
     /// assert!(hdl.is_commented());
     /// let hdl = Org::parse("* hello").first_node::<Headline>().unwrap();
     /// assert!(!hdl.is_commented());
+    ///
+    /// // COMMENT follows todo keyword and priority
+    /// let hdl = Org::parse("* TODO COMMENT foo").first_node::<Headline>().unwrap();
+    /// assert!(hdl.is_commented());
+    /// assert!(hdl.is_todo());
+    /// let hdl = Org::parse("* [#A] COMMENT foo").first_node::<Headline>().unwrap();
+    /// assert!(hdl.is_commented());
+    /// assert_eq!(hdl.priority().unwrap(), "A");
+    /// let hdl = Org::parse("* DONE [#B] COMMENT").first_node::<Headline>().unwrap();
+    /// assert!(hdl.is_commented());
+    /// let hdl = Org::parse("* COMMENT [#A] foo").first_node::<Headline>().unwrap();
+    /// assert!(hdl.is_commented());
+    /// let hdl = Org::parse("* TODO COMMENT :tag:").first_node::<Headline>().unwrap();
+    /// assert!(hdl.is_commented());
+    ///
+    /// // COMMENT must be a whole, uppercase word
+    /// let hdl = Org::parse("* TODO COMMENTS").first_node::<Headline>().unwrap();
+    /// assert!(!hdl.is_commented());
+    /// let hdl = Org::parse("* comment foo").first_node::<Headline>().unwrap();
+    /// assert!(!hdl.is_commented());
+    /// let hdl = Org::parse("* TODO foo COMMENT").first_node::<Headline>().unwrap();
+    /// assert!(!hdl.is_commented());
     /// ```
     pub fn is_commented(&self) -> bool {
         self.title()
@@ -185,6 +425,23 @@ impl Headline {
     }
 
     /// Returns this headline's scheduled timestamp, or `None` if not set.
+    ///
+    /// The whole planning line is available from [`Headline::planning`],
+    /// and its range can be used to rewrite or remove it:
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let mut org = Org::parse("* a\nSCHEDULED: <2024-01-01 Mon>\ntext\n");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.scheduled().unwrap().raw(), "<2024-01-01 Mon>");
+    ///
+    /// let planning = hdl.planning().unwrap();
+    /// assert_eq!(planning.raw(), "SCHEDULED: <2024-01-01 Mon>\n");
+    /// org.replace_range(planning.text_range(), "");
+    /// assert_eq!(org.to_org(), "* a\ntext\n");
+    /// assert!(org.first_node::<Headline>().unwrap().scheduled().is_none());
+    /// ```
     pub fn scheduled(&self) -> Option<Timestamp> {
         self.planning().and_then(|planning| planning.scheduled())
     }
@@ -219,6 +476,264 @@ impl Headline {
             .filter_map(filter_token(SyntaxKind::TEXT))
     }
 
+    /// Returns tags of this headline, including tags inherited from
+    /// `#+FILETAGS` and its ancestor headlines
+    ///
+    /// Tags are ordered from the outermost to this headline, and duplicated
+    /// tags are only returned once.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline, rowan::ast::AstNode};
+    ///
+    /// let org = Org::parse(r#"#+FILETAGS: :file:
+    /// * a :a:
+    /// ** b :b:file:
+    /// *** c :c:"#);
+    /// let hdl = org.document().syntax().descendants().filter_map(Headline::cast).last().unwrap();
+    /// assert_eq!(hdl.title_raw(), "c ");
+    /// assert_eq!(hdl.inherited_tags(), vec!["file", "a", "b", "c"]);
+    ///
+    /// let hdl = Org::parse("* a").first_node::<Headline>().unwrap();
+    /// assert!(hdl.inherited_tags().is_empty());
+    /// ```
+    pub fn inherited_tags(&self) -> Vec<String> {
+        let mut headlines: Vec<Headline> =
+            self.syntax.ancestors().filter_map(Headline::cast).collect();
+        headlines.reverse();
+
+        let mut tags = self
+            .syntax
+            .ancestors()
+            .last()
+            .and_then(Document::cast)
+            .map(|doc| doc.filetags())
+            .unwrap_or_default();
+
+        for tag in headlines.iter().flat_map(|hdl| hdl.tags()) {
+            if !tags.iter().any(|t| tag == *t) {
+                tags.push(tag.to_string());
+            }
+        }
+
+        tags
+    }
+
+    /// Returns value of given property, using default
+    /// [`PropertyInheritance`]
+    ///
+    /// Equivalent to `inherited_property_with(key, &PropertyInheritance::default())`.
+    pub fn inherited_property(&self, key: &str) -> Option<String> {
+        self.inherited_property_with(key, &PropertyInheritance::default())
+    }
+
+    /// Returns value of given property, looking up this headline, then its
+    /// ancestor headlines, then the top-level property drawer, then
+    /// top-level `#+PROPERTY` keywords
+    ///
+    /// Only this headline is looked up if the property isn't inherited
+    /// according to `inheritance`. Property keys are compared
+    /// case-insensitively.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{Headline, PropertyInheritance}, rowan::ast::AstNode};
+    ///
+    /// let org = Org::parse(r#":PROPERTIES:
+    /// :CATEGORY: work
+    /// :END:
+    /// #+PROPERTY: header-args :results silent
+    /// #+PROPERTY: CATEGORY home
+    /// * a
+    /// :PROPERTIES:
+    /// :OWNER: alice
+    /// :END:
+    /// ** b
+    /// :PROPERTIES:
+    /// :ID: b
+    /// :END:"#);
+    /// let hdl = org.document().syntax().descendants().filter_map(Headline::cast).last().unwrap();
+    /// assert_eq!(hdl.inherited_property("ID").unwrap(), "b");
+    /// assert_eq!(hdl.inherited_property("CATEGORY").unwrap(), "work");
+    /// assert!(hdl.inherited_property("owner").is_none());
+    /// assert!(hdl.inherited_property("header-args").is_none());
+    ///
+    /// let inheritance = PropertyInheritance::Only(vec!["OWNER".into()]);
+    /// assert_eq!(hdl.inherited_property_with("owner", &inheritance).unwrap(), "alice");
+    /// assert!(hdl.inherited_property_with("header-args", &inheritance).is_none());
+    ///
+    /// let inheritance = PropertyInheritance::All;
+    /// assert_eq!(hdl.inherited_property_with("header-args", &inheritance).unwrap(), ":results silent");
+    /// assert!(hdl.inherited_property_with("EFFORT", &inheritance).is_none());
+    /// ```
+    pub fn inherited_property_with(
+        &self,
+        key: &str,
+        inheritance: &PropertyInheritance,
+    ) -> Option<String> {
+        let find = |hdl: Headline| {
+            hdl.properties()?
+                .iter()
+                .find_map(|(k, v)| k.eq_ignore_ascii_case(key).then(|| v.trim().to_string()))
+        };
+
+        if !inheritance.is_inherited(key) {
+            return find(self.clone());
+        }
+
+        self.syntax
+            .ancestors()
+            .filter_map(Headline::cast)
+            .find_map(find)
+            .or_else(|| {
+                let document = self.syntax.ancestors().last().and_then(Document::cast)?;
+                document
+                    .properties()
+                    .and_then(|drawer| {
+                        drawer.iter().find_map(|(k, v)| {
+                            k.eq_ignore_ascii_case(key).then(|| v.trim().to_string())
+                        })
+                    })
+                    .or_else(|| {
+                        document
+                            .file_properties()
+                            .into_iter()
+                            .find_map(|(k, v)| k.eq_ignore_ascii_case(key).then_some(v))
+                    })
+            })
+    }
+
+    /// Returns the column view format of this headline
+    ///
+    /// The format is resolved from the `COLUMNS` property of this headline
+    /// or its ancestors, then the top-level `#+COLUMNS` keyword.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let org = Org::parse(r#"#+COLUMNS: %25ITEM %TODO
+    /// * a
+    /// * b
+    /// :PROPERTIES:
+    /// :COLUMNS: %ITEM %Effort{:}
+    /// :END:
+    /// ** c"#);
+    /// let columns = org.headlines().map(|h| h.columns().unwrap()).collect::<Vec<_>>();
+    /// assert_eq!(columns, vec!["%25ITEM %TODO", "%ITEM %Effort{:}", "%ITEM %Effort{:}"]);
+    ///
+    /// assert!(Org::parse("* a").first_node::<Headline>().unwrap().columns().is_none());
+    /// ```
+    pub fn columns(&self) -> Option<String> {
+        self.inherited_property("COLUMNS")
+            .filter(|c| !c.is_empty())
+            .or_else(|| {
+                let document = self.syntax.ancestors().last().and_then(Document::cast)?;
+                document.keyword("COLUMNS").filter(|c| !c.is_empty())
+            })
+    }
+
+    /// Returns the category of this headline, as used by agenda views
+    ///
+    /// The category is resolved from, in order, the `CATEGORY` property of
+    /// this headline or its ancestors, see [`Headline::inherited_property`],
+    /// and the last `#+CATEGORY` keyword before this headline.
+    ///
+    /// Returns `None` if neither is found. Org-mode then falls back to the
+    /// file name without extension, see [`Headline::category_with`].
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline, rowan::ast::AstNode};
+    ///
+    /// let org = Org::parse(r#"#+CATEGORY: home
+    /// * a
+    /// #+CATEGORY: work
+    /// * b
+    /// ** c
+    /// :PROPERTIES:
+    /// :CATEGORY: meetings
+    /// :END:
+    /// *** d"#);
+    /// let hdls = org.document().syntax().descendants().filter_map(Headline::cast).collect::<Vec<_>>();
+    /// let categories = hdls.iter().map(|h| h.category().unwrap()).collect::<Vec<_>>();
+    /// assert_eq!(categories, vec!["home", "work", "meetings", "meetings"]);
+    ///
+    /// let hdl = Org::parse("* a").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.category(), None);
+    /// ```
+    pub fn category(&self) -> Option<String> {
+        self.inherited_property("CATEGORY")
+            .filter(|c| !c.is_empty())
+            .or_else(|| {
+                let root = self.syntax.ancestors().last()?;
+                root.descendants()
+                    .take_while(|n| n.text_range().start() < self.start())
+                    .filter_map(Keyword::cast)
+                    .filter(|kw| kw.key().eq_ignore_ascii_case("CATEGORY"))
+                    .map(|kw| kw.value().trim().to_string())
+                    .filter(|c| !c.is_empty())
+                    .last()
+            })
+    }
+
+    /// Returns the category of this headline, falling back to the default
+    /// category of the file at given path
+    ///
+    /// Same as [`Headline::category`], except that a headline without
+    /// category gets [`Org::category_default`] of `path`, like in
+    /// Org-mode.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let org = Org::parse("* a\n:PROPERTIES:\n:CATEGORY: work\n:END:\n* b");
+    /// let categories = org
+    ///     .headlines()
+    ///     .map(|h| h.category_with("notes/todo.org").unwrap())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(categories, vec!["work", "todo"]);
+    ///
+    /// let hdl = Org::parse("* a").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.category_with(""), None);
+    /// ```
+    pub fn category_with(&self, path: impl AsRef<std::path::Path>) -> Option<String> {
+        self.category().or_else(|| Org::category_default(path))
+    }
+
+    /// Returns allowed values of given property
+    ///
+    /// Allowed values are declared in the `<KEY>_ALL` property, which is
+    /// resolved like [`Headline::inherited_property`]. Values are separated
+    /// by whitespace, and can be quoted with `"` to include whitespace.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let org = Org::parse(r#"#+PROPERTY: Effort_ALL 0 0:30 1:00
+    /// * a
+    /// :PROPERTIES:
+    /// :Status_ALL: open "in progress" done
+    /// :END:"#);
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.allowed_property_values("Effort"), vec!["0", "0:30", "1:00"]);
+    /// assert_eq!(hdl.allowed_property_values("Status"), vec!["open", "in progress", "done"]);
+    /// assert!(hdl.allowed_property_values("Owner").is_empty());
+    /// ```
+    pub fn allowed_property_values(&self, key: &str) -> Vec<String> {
+        let Some(value) = self.inherited_property(&format!("{key}_ALL")) else {
+            return vec![];
+        };
+
+        let mut values = vec![];
+        let mut rest = value.trim_start();
+        while !rest.is_empty() {
+            let (value, remaining) = match rest.strip_prefix('"') {
+                Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+                None => rest.split_once(char::is_whitespace).unwrap_or((rest, "")),
+            };
+            values.push(value.to_string());
+            rest = remaining.trim_start();
+        }
+        values
+    }
+
     /// Returns priority text
     ///
     /// ```rust
@@ -241,6 +756,139 @@ impl Headline {
             })
     }
 
+    /// Returns true if this headline's priority is within the range
+    /// configured in [`ParseConfig::priorities`]
+    ///
+    /// Headlines without a priority cookie are always in range.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ParseConfig, ast::Headline};
+    ///
+    /// let config = ParseConfig::default();
+    /// let hdl = Org::parse("* [#B] a").first_node::<Headline>().unwrap();
+    /// assert!(hdl.is_priority_in_range(&config));
+    /// let hdl = Org::parse("* [#Z] a").first_node::<Headline>().unwrap();
+    /// assert!(!hdl.is_priority_in_range(&config));
+    /// let hdl = Org::parse("* a").first_node::<Headline>().unwrap();
+    /// assert!(hdl.is_priority_in_range(&config));
+    /// ```
+    pub fn is_priority_in_range(&self, config: &ParseConfig) -> bool {
+        match self.priority_char() {
+            Some(c) => config.priority_rank(c).is_some(),
+            None => true,
+        }
+    }
+
+    /// Returns the rank of this headline's priority, where `0` is the
+    /// highest, using the range configured in [`ParseConfig::priorities`]
+    ///
+    /// Headlines without a priority cookie get the rank of the default
+    /// priority. Returns `None` if the priority is out of range.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ParseConfig, ast::Headline};
+    ///
+    /// let config = ParseConfig::default();
+    /// let hdl = Org::parse("* [#A] a").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.priority_value(&config), Some(0));
+    /// let hdl = Org::parse("* a").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.priority_value(&config), Some(1));
+    /// let hdl = Org::parse("* [#破] a").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.priority_value(&config), None);
+    ///
+    /// let config = ParseConfig { priorities: ('1', '9', '5'), ..Default::default() };
+    /// let org = config.clone().parse("* [#2] a\n* [#7] b\n* c");
+    /// let values = org
+    ///     .document()
+    ///     .headlines()
+    ///     .map(|hdl| hdl.priority_value(&config))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(values, vec![Some(1), Some(6), Some(4)]);
+    /// ```
+    pub fn priority_value(&self, config: &ParseConfig) -> Option<u32> {
+        config.priority_rank(self.priority_char().unwrap_or(config.priorities.2))
+    }
+
+    fn priority_char(&self) -> Option<char> {
+        self.priority()?.chars().next()
+    }
+
+    /// Returns a standalone [`Org`] containing only this headline and its
+    /// sub-headlines
+    ///
+    /// Syntax nodes share ownership of the tree they belong to, so a
+    /// `Headline` already stays valid after its `Org` is dropped. This
+    /// method is for detaching the subtree from the rest of the document,
+    /// e.g. to traverse or export it on its own.
+    ///
+    /// The syntax tree is copied as is. The returned `Org` uses the default
+    /// [`ParseConfig`], extended with todo keywords declared in the original
+    /// document and those used by headlines in this subtree, so they are
+    /// still recognized after editing it.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ParseConfig, ast::{Headline, TodoType}};
+    ///
+    /// let org = Org::parse("* a\n** b\ntext\n* c");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// drop(org);
+    /// let subtree = hdl.to_owned_subtree();
+    /// assert_eq!(subtree.to_org(), "* a\n** b\ntext\n");
+    /// assert_eq!(subtree.document().headlines().count(), 1);
+    /// assert_eq!(subtree.to_html(), "<main><h1>a</h1><h2>b</h2><section><p>text\n</p></section></main>");
+    ///
+    /// let org = Org::parse("#+TODO: WAIT | CANCELED\n* x\n** WAIT y");
+    /// let hdl = org.headlines().nth(1).unwrap();
+    /// let subtree = hdl.to_owned_subtree();
+    /// let hdl = subtree.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.todo_type(), Some(TodoType::Todo));
+    /// assert_eq!(hdl.title_raw(), "y");
+    /// assert_eq!(subtree.config().todo_keywords.0, ["TODO", "WAIT"]);
+    ///
+    /// let config = ParseConfig {
+    ///     todo_keywords: (vec!["NEXT".into()], vec!["FIXED".into()]),
+    ///     ..Default::default()
+    /// };
+    /// let org = config.parse("* NEXT x\n** FIXED y");
+    /// let subtree = org.first_node::<Headline>().unwrap().to_owned_subtree();
+    /// assert_eq!(subtree.config().todo_keywords.0, ["TODO", "NEXT"]);
+    /// assert_eq!(subtree.config().todo_keywords.1, ["DONE", "FIXED"]);
+    /// ```
+    pub fn to_owned_subtree(&self) -> Org {
+        let mut keywords = self
+            .syntax
+            .ancestors()
+            .last()
+            .and_then(Document::cast)
+            .map(|doc| doc.todo_keywords())
+            .unwrap_or_default();
+
+        for headline in self.syntax.descendants().filter_map(Headline::cast) {
+            let (Some(keyword), Some(ty)) = (headline.todo_keyword(), headline.todo_type()) else {
+                continue;
+            };
+            let list = match ty {
+                TodoType::Todo => &mut keywords.0,
+                TodoType::Done => &mut keywords.1,
+            };
+            if !list.iter().any(|k| keyword == *k) {
+                list.push(keyword.to_string());
+            }
+        }
+
+        let config = ParseConfig::default().with_buffer_todo_keywords(keywords);
+        Org {
+            green: GreenNode::new(
+                OrgLanguage::kind_to_raw(SyntaxKind::DOCUMENT),
+                [NodeOrToken::Node(self.syntax.green().into_owned())],
+            ),
+            base_config: config.clone(),
+            config,
+            names: Default::default(),
+            line_starts: Default::default(),
+        }
+    }
+
     /// Returns an iterator of clock element affiliated with this headline
     ///
     /// ```rust
@@ -260,12 +908,26 @@ impl Headline {
     /// let hdl = org.first_node::<Headline>().unwrap();
     /// assert_eq!(hdl.clocks().count(), 2);
     /// ```
+    ///
+    /// Clocks are collected from every `LOGBOOK` drawer in this headline's
+    /// section, whether or not planning or a property drawer precedes it:
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let org = Org::parse(r#"* DONE
+    /// CLOSED: [2024-10-12 Sat 10:00] SCHEDULED: <2024-10-12 Sat>
+    /// :PROPERTIES:
+    /// :ID: 1
+    /// :END:
+    /// :LOGBOOK:
+    /// CLOCK: [2024-10-12 Sat 09:00]--[2024-10-12 Sat 10:00] =>  1:00
+    /// :END:"#);
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.clocks().count(), 1);
+    /// ```
     pub fn clocks(&self) -> impl Iterator<Item = Clock> {
-        self.syntax
-            .children()
-            .flat_map(Section::cast)
-            .flat_map(|x| x.syntax.children().filter_map(Drawer::cast))
-            .filter(|d| d.name().eq_ignore_ascii_case("LOGBOOK"))
+        self.logbooks()
             .filter_map(|d| {
                 d.syntax
                     .children()
@@ -273,4 +935,616 @@ impl Headline {
             })
             .flat_map(|x| x.children().filter_map(Clock::cast))
     }
+
+    /// Returns the first `LOGBOOK` drawer in this headline's section
+    ///
+    /// The drawer holds both clock lines and state-change notes, so callers
+    /// can read the latter through [`Drawer::content_raw`].
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let org = Org::parse(r#"* TODO
+    /// SCHEDULED: <2024-10-12 Sat>
+    /// :PROPERTIES:
+    /// :ID: 1
+    /// :END:
+    /// :logbook:
+    /// - Note taken on [2024-10-12 Sat 10:00]
+    /// CLOCK: [2024-10-12 Sat 09:00]
+    /// :END:
+    /// text"#);
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// let logbook = hdl.logbook().unwrap();
+    /// assert_eq!(logbook.name(), "logbook");
+    /// assert!(logbook.content_raw().contains("Note taken on"));
+    ///
+    /// let hdl = Org::parse("* TODO\ntext").first_node::<Headline>().unwrap();
+    /// assert!(hdl.logbook().is_none());
+    /// ```
+    pub fn logbook(&self) -> Option<Drawer> {
+        self.logbooks().next()
+    }
+
+    /// Returns the title line with tags aligned to given column, like
+    /// `org-align-tags`
+    ///
+    /// If `column` is positive, tags start at that column. If negative,
+    /// tags end at its absolute value. Columns are counted in display
+    /// width, starting from zero. At least one space is kept before tags.
+    ///
+    /// Returns the title line unchanged, without line ending, if this
+    /// headline has no tags.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let hdl = Org::parse("* TODO title :a:b:\ntext").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.align_tags(20), "* TODO title        :a:b:");
+    /// assert_eq!(hdl.align_tags(-20), "* TODO title   :a:b:");
+    /// assert_eq!(hdl.align_tags(5), "* TODO title :a:b:");
+    ///
+    /// let hdl = Org::parse("* 日本語       :tag:").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.align_tags(-16), "* 日本語   :tag:");
+    ///
+    /// let hdl = Org::parse("* title").first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.align_tags(20), "* title");
+    /// ```
+    pub fn align_tags(&self, column: i32) -> String {
+        let text = self.syntax.to_string();
+        let line = &text[..text.find(['\r', '\n']).unwrap_or(text.len())];
+        match self.tags_padding(column) {
+            Some((range, padding)) => {
+                let start = usize::from(range.start() - self.start());
+                let end = usize::from(range.end() - self.start());
+                format!("{}{}{}", &line[..start], padding, &line[end..])
+            }
+            None => line.to_string(),
+        }
+    }
+
+    /// Returns the range of whitespace before tags, and whitespace that
+    /// aligns tags to given column
+    pub(crate) fn tags_padding(&self, column: i32) -> Option<(TextRange, String)> {
+        let tags = self.tags_range()?;
+        let text = self.syntax.to_string();
+        let tags = tags - self.start();
+        let prefix = text[..usize::from(tags.start())].trim_end_matches([' ', '\t']);
+
+        let width = display_width(prefix);
+        let tags_width = display_width(&text[tags]);
+        let target = if column >= 0 {
+            column as usize
+        } else {
+            (column.unsigned_abs() as usize).saturating_sub(tags_width)
+        };
+
+        let start = TextSize::from(prefix.len() as u32);
+        Some((
+            TextRange::new(start, tags.start()) + self.start(),
+            " ".repeat(target.saturating_sub(width).max(1)),
+        ))
+    }
+
+    /// Returns the range and replacement text that set tags of this
+    /// headline to given tags
+    ///
+    /// Tags that were aligned, i.e. preceded by more than one space, keep
+    /// ending at the same column. Removing all tags also removes the
+    /// whitespace before them.
+    pub(crate) fn tags_edit(&self, tags: &[String]) -> (TextRange, String) {
+        let text = self.syntax.to_string();
+        let line = &text[..text.find(['\r', '\n']).unwrap_or(text.len())];
+        let new_tags = if tags.is_empty() {
+            String::new()
+        } else {
+            format!(":{}:", tags.join(":"))
+        };
+
+        let (prefix, end, padding) = match self.tags_range() {
+            Some(range) => {
+                let range = range - self.start();
+                let prefix = line[..usize::from(range.start())].trim_end_matches([' ', '\t']);
+                let end = usize::from(range.end());
+                let padding = if new_tags.is_empty() {
+                    String::new()
+                } else if usize::from(range.start()) - prefix.len() > 1 {
+                    let target =
+                        display_width(&line[..end]).saturating_sub(display_width(&new_tags));
+                    " ".repeat(target.saturating_sub(display_width(prefix)).max(1))
+                } else {
+                    " ".into()
+                };
+                (prefix, end, padding)
+            }
+            None => {
+                let prefix = line.trim_end_matches([' ', '\t']);
+                let padding = if new_tags.is_empty() { "" } else { " " };
+                (prefix, prefix.len(), padding.into())
+            }
+        };
+
+        (
+            TextRange::new(
+                TextSize::from(prefix.len() as u32),
+                TextSize::from(end as u32),
+            ) + self.start(),
+            padding + &new_tags,
+        )
+    }
+
+    /// Returns the range and replacement text that set todo keyword,
+    /// priority and title of this headline to given values
+    ///
+    /// The range starts after the stars and ends before the whitespace
+    /// preceding tags, so tags and their alignment are left untouched.
+    pub(crate) fn title_line_edit(
+        &self,
+        keyword: Option<&str>,
+        priority: Option<&str>,
+        title: &str,
+    ) -> (TextRange, String) {
+        let start = self.stars_range().map_or(self.start(), |r| r.end());
+
+        let title_end = self
+            .syntax
+            .children()
+            .find(|n| n.kind() == SyntaxKind::HEADLINE_TITLE)
+            .map(|n| {
+                let text = n.to_string();
+                n.text_range().start() + TextSize::from(text.trim_end().len() as u32)
+            });
+        let end = [
+            title_end,
+            self.priority_range().map(|r| r.end()),
+            self.todo_range().map(|r| r.end()),
+        ]
+        .into_iter()
+        .flatten()
+        .fold(start, TextSize::max);
+
+        // trailing whitespace without tags is dropped
+        let line = self.syntax.to_string();
+        let line = &line[..line.find(['\r', '\n']).unwrap_or(line.len())];
+        let rest = &line[usize::from(end - self.start())..];
+        let end = if rest.trim().is_empty() {
+            end + TextSize::from(rest.len() as u32)
+        } else {
+            end
+        };
+
+        let title = title.replace(['\r', '\n'], " ");
+        let mut text = String::new();
+        for part in [
+            keyword.map(Cow::from),
+            priority.map(|p| Cow::from(format!("[#{p}]"))),
+            Some(Cow::from(title.trim())).filter(|t| !t.is_empty()),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            text.push(' ');
+            text.push_str(&part);
+        }
+
+        // headline stars must be followed by whitespace
+        if text.is_empty() && !line[usize::from(end - self.start())..].starts_with([' ', '\t']) {
+            text.push(' ');
+        }
+
+        (TextRange::new(start, end), text)
+    }
+
+    fn logbooks(&self) -> impl Iterator<Item = Drawer> {
+        self.syntax
+            .children()
+            .flat_map(Section::cast)
+            .flat_map(|x| x.syntax.children().filter_map(Drawer::cast))
+            .filter(|d| d.name().eq_ignore_ascii_case("LOGBOOK"))
+    }
+}
+
+impl Org {
+    /// Returns the default category for a file at given path, which is the
+    /// file name without extension
+    ///
+    /// Org-mode uses it for headlines without a category, see
+    /// [`Headline::category_with`].
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// assert_eq!(Org::category_default("~/org/inbox.org").unwrap(), "inbox");
+    /// assert_eq!(Org::category_default("journal").unwrap(), "journal");
+    /// assert_eq!(Org::category_default(""), None);
+    /// ```
+    pub fn category_default(path: impl AsRef<std::path::Path>) -> Option<String> {
+        path.as_ref()
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+    }
+
+    /// Aligns tags of all headlines to given column, see
+    /// [`Headline::align_tags`]
+    ///
+    /// The document is parsed again with current config.
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// let mut org = Org::parse("* a :x:\n** bb :y:z:\ntext :not:tag:\n* c");
+    /// org.align_all_tags(-12);
+    /// assert_eq!(org.to_org(), "* a      :x:\n** bb  :y:z:\ntext :not:tag:\n* c");
+    /// ```
+    pub fn align_all_tags(&mut self, column: i32) {
+        let edits = self
+            .document()
+            .syntax
+            .descendants()
+            .filter_map(Headline::cast)
+            .filter_map(|hdl| hdl.tags_padding(column))
+            .collect::<Vec<_>>();
+
+        if edits.is_empty() {
+            return;
+        }
+
+        let mut text = self.to_org();
+        for (range, padding) in edits.iter().rev() {
+            text.replace_range(
+                usize::from(range.start())..usize::from(range.end()),
+                padding,
+            );
+        }
+        *self = self.base_config.clone().parse(text);
+    }
+
+    /// Adds a tag to given headline, and reparses the syntax tree with
+    /// current config
+    ///
+    /// Tag is sanitized with [`escape::tag`]. Existing tags keep their
+    /// alignment, see [`Org::align_all_tags`]. Returns `false` if the
+    /// headline already has this tag.
+    ///
+    /// Since the syntax tree is changed, headlines have to be queried again.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let mut org = Org::parse("* TODO a\n* b          :x:\ntext");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert!(org.add_tag(&hdl, "ARCHIVE"));
+    /// assert_eq!(org.to_org(), "* TODO a :ARCHIVE:\n* b          :x:\ntext");
+    ///
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert!(!org.add_tag(&hdl, "ARCHIVE"));
+    ///
+    /// let hdl = org.document().headlines().nth(1).unwrap();
+    /// assert!(org.add_tag(&hdl, "@home"));
+    /// assert_eq!(org.to_org(), "* TODO a :ARCHIVE:\n* b    :x:@home:\ntext");
+    /// ```
+    pub fn add_tag(&mut self, headline: &Headline, tag: &str) -> bool {
+        let tag = escape::tag(tag);
+        let mut tags = headline.tags().map(|t| t.to_string()).collect::<Vec<_>>();
+        if tag.is_empty() || tags.contains(&tag) {
+            return false;
+        }
+        tags.push(tag);
+        let (range, text) = headline.tags_edit(&tags);
+        self.replace_range(range, text);
+        true
+    }
+
+    /// Replaces the title of given headline, and reparses the syntax tree
+    /// with current config
+    ///
+    /// Stars, todo keyword, priority and tags are kept. `title` is sanitized
+    /// with [`escape::headline_title_with`], so it can't be parsed as todo
+    /// keyword, priority or tags, and an empty `title` leaves a bare
+    /// headline.
+    ///
+    /// Since the syntax tree is changed, headlines have to be queried again.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{Bold, Headline}};
+    ///
+    /// let mut org = Org::parse("** TODO [#A] old title   :work:\nbody\n* next");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// org.set_title(&hdl, "new *title*");
+    /// assert_eq!(org.to_org(), "** TODO [#A] new *title*   :work:\nbody\n* next");
+    /// assert!(org.first_node::<Bold>().is_some());
+    ///
+    /// let mut org = Org::parse("* a\n** b");
+    /// org.set_title(&org.first_node::<Headline>().unwrap(), "");
+    /// assert_eq!(org.to_org(), "* \n** b");
+    /// org.set_title(&org.first_node::<Headline>().unwrap(), "c");
+    /// assert_eq!(org.to_org(), "* c\n** b");
+    ///
+    /// let mut org = Org::parse("* :x:");
+    /// org.set_title(&org.first_node::<Headline>().unwrap(), "d");
+    /// assert_eq!(org.to_org(), "* d :x:");
+    ///
+    /// let mut org = Org::parse("* a");
+    /// org.set_title(&org.first_node::<Headline>().unwrap(), "fix :bug:");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.tags().count(), 0);
+    /// assert_eq!(hdl.title_raw(), "fix :bug:\u{200B}");
+    ///
+    /// org.set_title(&hdl, "DONE reading");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert!(hdl.todo_keyword().is_none());
+    /// assert_eq!(hdl.title_raw(), "\u{200B}DONE reading");
+    /// ```
+    pub fn set_title(&mut self, headline: &Headline, title: &str) {
+        let title = escape::headline_title_with(title, &self.config);
+        let keyword = headline.todo_keyword();
+        let priority = headline.priority();
+        let (range, text) =
+            headline.title_line_edit(keyword.as_deref(), priority.as_deref(), &title);
+        self.replace_range(range, text);
+    }
+
+    /// Sets or removes the todo keyword of given headline, and reparses the
+    /// syntax tree with current config
+    ///
+    /// The keyword is only recognized if it's declared in
+    /// [`ParseConfig::todo_keywords`](crate::ParseConfig::todo_keywords),
+    /// otherwise it becomes part of the title.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{Headline, TodoType}};
+    ///
+    /// let mut org = Org::parse("* foo :x:");
+    /// org.set_todo_keyword(&org.first_node::<Headline>().unwrap(), Some("TODO"));
+    /// assert_eq!(org.to_org(), "* TODO foo :x:");
+    ///
+    /// org.set_todo_keyword(&org.first_node::<Headline>().unwrap(), Some("DONE"));
+    /// assert_eq!(org.to_org(), "* DONE foo :x:");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.todo_type(), Some(TodoType::Done));
+    ///
+    /// org.set_todo_keyword(&hdl, None);
+    /// assert_eq!(org.to_org(), "* foo :x:");
+    ///
+    /// let mut org = Org::parse("** TODO\nbody");
+    /// org.set_todo_keyword(&org.first_node::<Headline>().unwrap(), None);
+    /// assert_eq!(org.to_org(), "** \nbody");
+    /// ```
+    pub fn set_todo_keyword(&mut self, headline: &Headline, keyword: Option<&str>) {
+        let keyword = keyword.map(str::trim).filter(|k| !k.is_empty());
+        let priority = headline.priority();
+        let title = headline.title_raw();
+        let (range, text) = headline.title_line_edit(keyword, priority.as_deref(), &title);
+        self.replace_range(range, text);
+    }
+
+    /// Sets or removes the priority cookie of given headline, and reparses
+    /// the syntax tree with current config
+    ///
+    /// The cookie is placed between todo keyword and title.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let mut org = Org::parse("* TODO foo");
+    /// org.set_priority(&org.first_node::<Headline>().unwrap(), Some('A'));
+    /// assert_eq!(org.to_org(), "* TODO [#A] foo");
+    ///
+    /// org.set_priority(&org.first_node::<Headline>().unwrap(), Some('C'));
+    /// assert_eq!(org.to_org(), "* TODO [#C] foo");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.priority().unwrap(), "C");
+    ///
+    /// org.set_priority(&hdl, None);
+    /// assert_eq!(org.to_org(), "* TODO foo");
+    ///
+    /// let mut org = Org::parse("* foo");
+    /// org.set_priority(&org.first_node::<Headline>().unwrap(), Some('B'));
+    /// assert_eq!(org.to_org(), "* [#B] foo");
+    /// ```
+    pub fn set_priority(&mut self, headline: &Headline, priority: Option<char>) {
+        let keyword = headline.todo_keyword();
+        let priority = priority.map(String::from);
+        let title = headline.title_raw();
+        let (range, text) =
+            headline.title_line_edit(keyword.as_deref(), priority.as_deref(), &title);
+        self.replace_range(range, text);
+    }
+
+    /// Removes a tag from given headline, and reparses the syntax tree
+    /// with current config
+    ///
+    /// Returns `false` if the headline doesn't have this tag.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let mut org = Org::parse("* a   :x:ARCHIVE:\n** b :ARCHIVE:");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert!(org.remove_tag(&hdl, "ARCHIVE"));
+    /// assert_eq!(org.to_org(), "* a           :x:\n** b :ARCHIVE:");
+    ///
+    /// let hdl = org.document().headlines().next().unwrap().headlines().next().unwrap();
+    /// assert!(org.remove_tag(&hdl, "ARCHIVE"));
+    /// assert_eq!(org.to_org(), "* a           :x:\n** b");
+    /// assert!(!org.remove_tag(&org.first_node::<Headline>().unwrap(), "y"));
+    /// ```
+    pub fn remove_tag(&mut self, headline: &Headline, tag: &str) -> bool {
+        let mut tags = headline.tags().map(|t| t.to_string()).collect::<Vec<_>>();
+        let len = tags.len();
+        tags.retain(|t| t != tag);
+        if tags.len() == len {
+            return false;
+        }
+        let (range, text) = headline.tags_edit(&tags);
+        self.replace_range(range, text);
+        true
+    }
+
+    /// Adds a tag to given headline if it doesn't have it, removes it
+    /// otherwise
+    ///
+    /// Returns `true` if the headline has this tag afterwards.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let mut org = Org::parse("* a :x:");
+    /// assert!(org.toggle_tag(&org.first_node::<Headline>().unwrap(), "ARCHIVE"));
+    /// assert_eq!(org.to_org(), "* a :x:ARCHIVE:");
+    /// assert!(!org.toggle_tag(&org.first_node::<Headline>().unwrap(), "ARCHIVE"));
+    /// assert_eq!(org.to_org(), "* a :x:");
+    /// ```
+    pub fn toggle_tag(&mut self, headline: &Headline, tag: &str) -> bool {
+        if headline.tags().any(|t| t == tag) {
+            !self.remove_tag(headline, tag)
+        } else {
+            self.add_tag(headline, tag)
+        }
+    }
+
+    /// Removes given headline and its subtree, and reparses the syntax tree
+    /// with current config
+    ///
+    /// Blank lines before the removed subtree are collapsed into one, so
+    /// the headlines around it are separated as before. Returns the
+    /// removed subtree, without its trailing blank lines, as a standalone
+    /// document parsed with current config.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Headline};
+    ///
+    /// let mut org = Org::parse("* a\ntext\n\n\n* b\n** c\n\n* d");
+    /// let hdl = org.document().headlines().nth(1).unwrap();
+    /// let cut = org.cut_subtree(&hdl);
+    /// assert_eq!(cut.to_org(), "* b\n** c\n");
+    /// assert_eq!(org.to_org(), "* a\ntext\n\n* d");
+    ///
+    /// let hdl = org.document().headlines().nth(1).unwrap();
+    /// org.cut_subtree(&hdl);
+    /// assert_eq!(org.to_org(), "* a\ntext\n\n");
+    ///
+    /// let mut org = Org::parse("* a\n* b\n\n* c\n");
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(org.cut_subtree(&hdl).to_org(), "* a\n");
+    /// assert_eq!(org.to_org(), "* b\n\n* c\n");
+    /// ```
+    pub fn cut_subtree(&mut self, headline: &Headline) -> Org {
+        let text = self.to_org();
+        let start = usize::from(headline.start());
+        let end = usize::from(headline.end());
+
+        let subtree = &text[start..end];
+        let content = subtree.trim_end();
+        let line_end = subtree[content.len()..]
+            .find('\n')
+            .map(|i| i + 1)
+            .unwrap_or(subtree.len() - content.len());
+        let cut = self
+            .config
+            .clone()
+            .parse(&subtree[..content.len() + line_end]);
+
+        // keep the first blank line before the subtree, if any
+        let before = &text[..start];
+        let content_end = match before.trim_end().len() {
+            0 => 0,
+            len => before[len..]
+                .find('\n')
+                .map(|i| len + i + 1)
+                .unwrap_or(start),
+        };
+        let keep_end = before[content_end..]
+            .find('\n')
+            .map(|i| content_end + i + 1)
+            .unwrap_or(start);
+
+        self.replace_range(
+            TextRange::new(TextSize::from(keep_end as u32), TextSize::from(end as u32)),
+            "",
+        );
+
+        cut
+    }
+
+    /// Decreases the level of given headline and its subtree, and reparses
+    /// the syntax tree with current config
+    ///
+    /// Levels change by two if [`ParseConfig::odd_levels_only`] is enabled,
+    /// so headlines stay at odd levels. Returns `false` if the headline is
+    /// already at the top level.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ParseConfig, ast::Headline};
+    ///
+    /// let mut org = Org::parse("* a\n** b\n*** c\n");
+    /// let hdl = org.document().headlines().next().unwrap().headlines().next().unwrap();
+    /// assert!(org.promote_subtree(&hdl));
+    /// assert_eq!(org.to_org(), "* a\n* b\n** c\n");
+    ///
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert!(!org.promote_subtree(&hdl));
+    ///
+    /// let config = ParseConfig { odd_levels_only: true, ..Default::default() };
+    /// let mut org = config.parse("* a\n*** b\n***** c\n");
+    /// let hdl = org.document().headlines().next().unwrap().headlines().next().unwrap();
+    /// assert!(org.promote_subtree(&hdl));
+    /// assert_eq!(org.to_org(), "* a\n* b\n*** c\n");
+    /// ```
+    pub fn promote_subtree(&mut self, headline: &Headline) -> bool {
+        let step = if self.config.odd_levels_only { 2 } else { 1 };
+        if headline.level() <= step {
+            return false;
+        }
+        self.shift_subtree(headline, |level| level - step);
+        true
+    }
+
+    /// Increases the level of given headline and its subtree, and reparses
+    /// the syntax tree with current config
+    ///
+    /// Levels change by two if [`ParseConfig::odd_levels_only`] is enabled,
+    /// so headlines stay at odd levels.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ParseConfig, ast::Headline};
+    ///
+    /// let mut org = Org::parse("* a\n* b\n** c\n");
+    /// let hdl = org.document().headlines().nth(1).unwrap();
+    /// org.demote_subtree(&hdl);
+    /// assert_eq!(org.to_org(), "* a\n** b\n*** c\n");
+    /// assert_eq!(org.first_node::<Headline>().unwrap().headlines().count(), 1);
+    ///
+    /// let config = ParseConfig { odd_levels_only: true, ..Default::default() };
+    /// let mut org = config.parse("* a\n* b\n*** c\n");
+    /// let hdl = org.document().headlines().nth(1).unwrap();
+    /// org.demote_subtree(&hdl);
+    /// assert_eq!(org.to_org(), "* a\n*** b\n***** c\n");
+    /// let b = org.first_node::<Headline>().unwrap().headlines().next().unwrap();
+    /// assert_eq!((b.level(), b.headlines().next().unwrap().level()), (3, 5));
+    /// ```
+    pub fn demote_subtree(&mut self, headline: &Headline) {
+        let step = if self.config.odd_levels_only { 2 } else { 1 };
+        self.shift_subtree(headline, |level| level + step);
+    }
+
+    fn shift_subtree(&mut self, headline: &Headline, level: impl Fn(usize) -> usize) {
+        let mut text = self.to_org();
+        let headlines = headline
+            .syntax
+            .descendants()
+            .filter_map(Headline::cast)
+            .collect::<Vec<_>>();
+        for hdl in headlines.iter().rev() {
+            if let Some(range) = hdl.stars_range() {
+                text.replace_range(
+                    usize::from(range.start())..usize::from(range.end()),
+                    &"*".repeat(level(hdl.level())),
+                );
+            }
+        }
+        // demoted headlines may become children of their previous sibling,
+        // so the whole document is parsed again
+        *self = self.base_config.clone().parse(text);
+    }
 }
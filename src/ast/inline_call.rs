@@ -27,6 +27,9 @@ impl InlineCall {
     ///
     /// let call = Org::parse("call_square(4)[:results html]").first_node::<InlineCall>().unwrap();
     /// assert!(call.inside_header().is_none());
+    ///
+    /// let call = Org::parse("call_square[](4)").first_node::<InlineCall>().unwrap();
+    /// assert!(call.inside_header().is_none());
     /// ```
     pub fn inside_header(&self) -> Option<Token> {
         self.syntax
@@ -38,6 +41,7 @@ impl InlineCall {
                 debug_assert_eq!(e.kind(), SyntaxKind::TEXT);
                 Some(Token(e.into_token()?))
             })
+            .filter(|t| !t.trim().is_empty())
     }
 
     ///
@@ -46,6 +50,11 @@ impl InlineCall {
     ///
     /// let call = Org::parse("call_square(4)").first_node::<InlineCall>().unwrap();
     /// assert_eq!(call.arguments(), "4");
+    /// let call = Org::parse("call_add[:var x=1](2, 3)").first_node::<InlineCall>().unwrap();
+    /// assert_eq!(call.call(), "add");
+    /// assert_eq!(call.arguments(), "2, 3");
+    /// let call = Org::parse("call_now()").first_node::<InlineCall>().unwrap();
+    /// assert_eq!(call.arguments(), "");
     /// ```
     pub fn arguments(&self) -> Token {
         self.syntax
@@ -64,17 +73,22 @@ impl InlineCall {
     ///
     /// let call = Org::parse("call_square[:results output](4)").first_node::<InlineCall>().unwrap();
     /// assert!(call.end_header().is_none());
+    ///
+    /// let call = Org::parse("call_square(4)[:results html]").first_node::<InlineCall>().unwrap();
+    /// assert_eq!(call.end_header().unwrap(), ":results html");
+    /// let call = Org::parse("call_square(4)[]").first_node::<InlineCall>().unwrap();
+    /// assert!(call.end_header().is_none());
     /// ```
     pub fn end_header(&self) -> Option<Token> {
         self.syntax
             .children_with_tokens()
-            .skip_while(|e| e.kind() != SyntaxKind::L_BRACKET)
-            .skip(1)
+            .skip_while(|e| e.kind() != SyntaxKind::R_PARENS)
             .skip_while(|e| e.kind() != SyntaxKind::L_BRACKET)
             .nth(1)
             .and_then(|e| {
                 debug_assert_eq!(e.kind(), SyntaxKind::TEXT);
                 Some(Token(e.into_token()?))
             })
+            .filter(|t| !t.trim().is_empty())
     }
 }
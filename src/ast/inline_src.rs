@@ -30,6 +30,13 @@ impl InlineSrc {
     /// assert!(s.parameters().is_none());
     /// let s = Org::parse("src_xml[:exports code]{<tag>text</tag>}").first_node::<InlineSrc>().unwrap();
     /// assert_eq!(s.parameters().unwrap(), ":exports code");
+    /// let s = Org::parse("src_python[:exports both :results output]{print(1)}").first_node::<InlineSrc>().unwrap();
+    /// assert_eq!(s.language(), "python");
+    /// assert_eq!(s.parameters().unwrap(), ":exports both :results output");
+    /// assert_eq!(s.value(), "print(1)");
+    ///
+    /// let s = Org::parse("src_C[]{int a = 0;}").first_node::<InlineSrc>().unwrap();
+    /// assert!(s.parameters().is_none());
     /// ```
     pub fn parameters(&self) -> Option<Token> {
         self.syntax
@@ -40,6 +47,7 @@ impl InlineSrc {
                 debug_assert_eq!(n.kind(), SyntaxKind::TEXT);
                 Some(Token(n.into_token()?))
             })
+            .filter(|t| !t.trim().is_empty())
     }
 
     /// Source code
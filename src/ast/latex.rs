@@ -0,0 +1,112 @@
+use crate::SyntaxKind;
+
+use super::{filter_token, LatexEnvironment, LatexFragment, Token};
+
+/// Delimiters of a LaTeX fragment, returned by [`LatexFragment::delimiter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatexDelimiter {
+    /// `$...$`
+    Dollar,
+    /// `$$...$$`
+    DoubleDollar,
+    /// `\(...\)`
+    Parens,
+    /// `\[...\]`
+    Brackets,
+    /// `\NAME{...}` or `\NAME[...]`
+    Command,
+}
+
+impl LatexFragment {
+    /// Returns the delimiters of this fragment
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{LatexDelimiter, LatexFragment}};
+    ///
+    /// let f = Org::parse("$a+b$").first_node::<LatexFragment>().unwrap();
+    /// assert_eq!(f.delimiter(), LatexDelimiter::Dollar);
+    /// let f = Org::parse("$$a+b$$").first_node::<LatexFragment>().unwrap();
+    /// assert_eq!(f.delimiter(), LatexDelimiter::DoubleDollar);
+    /// let f = Org::parse("\\(a+b\\)").first_node::<LatexFragment>().unwrap();
+    /// assert_eq!(f.delimiter(), LatexDelimiter::Parens);
+    /// let f = Org::parse("\\[a+b\\]").first_node::<LatexFragment>().unwrap();
+    /// assert_eq!(f.delimiter(), LatexDelimiter::Brackets);
+    /// let f = Org::parse("\\sqrt{2}").first_node::<LatexFragment>().unwrap();
+    /// assert_eq!(f.delimiter(), LatexDelimiter::Command);
+    /// ```
+    pub fn delimiter(&self) -> LatexDelimiter {
+        let mut kinds = self.syntax.children_with_tokens().map(|e| e.kind());
+        match (kinds.next(), kinds.next()) {
+            (Some(SyntaxKind::DOLLAR), _) => LatexDelimiter::Dollar,
+            (Some(SyntaxKind::DOLLAR2), _) => LatexDelimiter::DoubleDollar,
+            (Some(SyntaxKind::BACKSLASH), Some(SyntaxKind::L_PARENS)) => LatexDelimiter::Parens,
+            (Some(SyntaxKind::BACKSLASH), Some(SyntaxKind::L_BRACKET)) => LatexDelimiter::Brackets,
+            _ => LatexDelimiter::Command,
+        }
+    }
+
+    /// Returns LaTeX code inside delimiters
+    ///
+    /// For [`LatexDelimiter::Command`], the whole fragment is returned,
+    /// since the command name is part of the code.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::LatexFragment};
+    ///
+    /// let f = Org::parse("the sum $a+b$ is").first_node::<LatexFragment>().unwrap();
+    /// assert_eq!(f.value(), "a+b");
+    /// let f = Org::parse("\\(e^{i \\pi}\\)").first_node::<LatexFragment>().unwrap();
+    /// assert_eq!(f.value(), "e^{i \\pi}");
+    /// let f = Org::parse("\\sqrt{2}").first_node::<LatexFragment>().unwrap();
+    /// assert_eq!(f.value(), "\\sqrt{2}");
+    ///
+    /// // a single dollar sign isn't a fragment
+    /// assert!(Org::parse("costs $5 and $6").first_node::<LatexFragment>().is_none());
+    /// ```
+    pub fn value(&self) -> String {
+        match self.delimiter() {
+            LatexDelimiter::Command => self.syntax.to_string(),
+            _ => self
+                .syntax
+                .children_with_tokens()
+                .find_map(filter_token(SyntaxKind::TEXT))
+                .map(|t| t.to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl LatexEnvironment {
+    /// Returns the name of this environment
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::LatexEnvironment};
+    ///
+    /// let env = Org::parse("\\begin{align*}\na &= b\n\\end{align*}\n")
+    ///     .first_node::<LatexEnvironment>()
+    ///     .unwrap();
+    /// assert_eq!(env.name(), "align*");
+    /// ```
+    pub fn name(&self) -> Token {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(filter_token(SyntaxKind::TEXT))
+            .nth(1)
+            .expect("latex environment must contains name")
+    }
+
+    /// Returns LaTeX code of this environment, from `\begin` to `\end`
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::LatexEnvironment};
+    ///
+    /// let env = Org::parse("  \\begin{align}\n  a &= b \\\\\n  c &= d\n  \\end{align}  \n\ntext")
+    ///     .first_node::<LatexEnvironment>()
+    ///     .unwrap();
+    /// assert_eq!(env.name(), "align");
+    /// assert_eq!(env.value(), "\\begin{align}\n  a &= b \\\\\n  c &= d\n  \\end{align}");
+    /// ```
+    pub fn value(&self) -> String {
+        self.syntax.to_string().trim().to_string()
+    }
+}
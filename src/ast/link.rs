@@ -1,7 +1,13 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
 use rowan::ast::AstNode;
 
 use super::{token, AffiliatedKeyword, Link, Paragraph, Token};
-use crate::{syntax::SyntaxKind, SyntaxElement};
+use crate::{
+    syntax::{link::link_type, SyntaxKind},
+    SyntaxElement,
+};
 
 impl Link {
     /// Returns link destination
@@ -15,11 +21,121 @@ impl Link {
     /// assert_eq!(link.path(), "https://google.com");
     /// let link = Org::parse("[[https://google.com][Google]]").first_node::<Link>().unwrap();
     /// assert_eq!(link.path(), "https://google.com");
+    /// let link = Org::parse("<https://google.com>").first_node::<Link>().unwrap();
+    /// assert_eq!(link.path(), "https://google.com");
+    /// let link = Org::parse("see https://google.com.").first_node::<Link>().unwrap();
+    /// assert_eq!(link.path(), "https://google.com");
     /// ```
     pub fn path(&self) -> Token {
         token(&self.syntax, SyntaxKind::LINK_PATH).expect("link must contains LINK_PATH")
     }
 
+    /// Returns link type of this link, like `https`, `file`, `id` or
+    /// `mailto`
+    ///
+    /// Returns `None` if the path doesn't start with a known link type.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Link};
+    ///
+    /// let link = Org::parse("[[https://x][y]]").first_node::<Link>().unwrap();
+    /// assert_eq!(link.protocol(), Some("https"));
+    /// assert_eq!(link.description_raw(), "y");
+    ///
+    /// let link = Org::parse("[[file:a.org]]").first_node::<Link>().unwrap();
+    /// assert_eq!(link.protocol(), Some("file"));
+    /// assert!(!link.has_description());
+    ///
+    /// let link = Org::parse("see https://example.com").first_node::<Link>().unwrap();
+    /// assert_eq!(link.protocol(), Some("https"));
+    /// assert_eq!(link.path(), "https://example.com");
+    /// assert!(!link.has_description());
+    ///
+    /// let link = Org::parse("[[#custom-id]]").first_node::<Link>().unwrap();
+    /// assert_eq!(link.protocol(), None);
+    /// ```
+    pub fn protocol(&self) -> Option<&'static str> {
+        link_type(&self.path())
+    }
+
+    /// Returns `true` if this link points into the current document
+    ///
+    /// Internal links are `id:` links and links without a `word:` prefix,
+    /// like `#custom-id`, `*Headline`, `(coderef)` or a target name, except
+    /// paths to files starting with `/`, `.` or `~`. Any other prefix,
+    /// including link abbreviations like `gh:`, makes the link external.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Link};
+    ///
+    /// let is_internal = |s: &str| Org::parse(s).first_node::<Link>().unwrap().is_internal();
+    /// assert!(is_internal("[[#custom-id]]"));
+    /// assert!(is_internal("[[*Headline]]"));
+    /// assert!(is_internal("[[id:20240101T000000]]"));
+    /// assert!(is_internal("[[target][Target]]"));
+    /// assert!(!is_internal("[[https://x][y]]"));
+    /// assert!(!is_internal("[[file:a.org]]"));
+    /// assert!(!is_internal("[[./a.org]]"));
+    /// assert!(!is_internal("[[gh:Xuanwo/orgize]]"));
+    /// assert!(!is_internal("[[news:comp.emacs]]"));
+    /// ```
+    pub fn is_internal(&self) -> bool {
+        let path = self.path();
+        match path.split_once(':') {
+            Some((ty, _))
+                if !ty.is_empty()
+                    && ty
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '+')) =>
+            {
+                ty == "id"
+            }
+            _ => !path.is_empty() && !path.starts_with(['/', '.', '~']),
+        }
+    }
+
+    /// Returns link destination with abbreviation expanded
+    ///
+    /// Link abbreviations, usually collected by
+    /// [`Document::link_abbreviations`](super::Document::link_abbreviations),
+    /// map a prefix to a replacement text. In the replacement, `%s` is
+    /// replaced by the rest of the link, and `%h` by the rest of the link
+    /// percent-encoded. If it contains neither, the rest of the link is
+    /// appended.
+    ///
+    /// Returns the path unchanged if it doesn't start with an abbreviation.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Link, rowan::ast::AstNode};
+    ///
+    /// let org = Org::parse(r#"#+LINK: gh https://github.com/%s
+    /// #+LINK: search https://duckduckgo.com/?q=%h
+    /// #+LINK: wiki https://en.wikipedia.org/wiki/
+    /// [[gh:Xuanwo/orgize]] [[search:org mode]] [[wiki:Org-mode]] [[https://example.com]]"#);
+    /// let abbrevs = org.link_abbreviations();
+    /// let links = org.document().syntax().descendants().filter_map(Link::cast).collect::<Vec<_>>();
+    /// assert_eq!(links[0].expand(&abbrevs), "https://github.com/Xuanwo/orgize");
+    /// assert_eq!(links[1].expand(&abbrevs), "https://duckduckgo.com/?q=org%20mode");
+    /// assert_eq!(links[2].expand(&abbrevs), "https://en.wikipedia.org/wiki/Org-mode");
+    /// assert_eq!(links[3].expand(&abbrevs), "https://example.com");
+    /// ```
+    pub fn expand(&self, abbrevs: &HashMap<String, String>) -> String {
+        let path = self.path();
+        let (abbrev, rest) = path.split_once(':').unwrap_or((&path, ""));
+
+        let Some(replacement) = abbrevs.get(abbrev) else {
+            return path.to_string();
+        };
+
+        if replacement.contains("%s") {
+            replacement.replace("%s", rest)
+        } else if replacement.contains("%h") {
+            replacement.replace("%h", &url_hexify(rest))
+        } else {
+            format!("{replacement}{rest}")
+        }
+    }
+
     /// Returns `true` if link contains description
     ///
     /// ```rust
@@ -119,3 +235,17 @@ impl Link {
         Paragraph::cast(self.syntax.parent()?.clone())?.caption()
     }
 }
+
+/// Percent-encodes all characters except unreserved ones, like
+/// `url-hexify-string`
+fn url_hexify(s: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            output.push(byte as char);
+        } else {
+            let _ = write!(&mut output, "%{byte:02X}");
+        }
+    }
+    output
+}
@@ -1,11 +1,24 @@
-use super::{filter_token, List, ListItem, Token};
-use crate::{syntax::SyntaxKind, SyntaxElement};
+use rowan::{ast::AstNode, TextRange};
+
+use super::{filter_token, Cookie, List, ListItem, Token};
+use crate::{syntax::SyntaxKind, SyntaxElement, SyntaxNode};
+
+/// State of a checkbox, returned by [`ListItem::checkbox_state`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckboxState {
+    /// `[ ]`
+    Empty,
+    /// `[X]`
+    Checked,
+    /// `[-]`
+    Partial,
+}
 
 impl List {
     /// Returns `true` if this list is an ordered link
     ///
     /// ```rust
-    /// use orgize::{Org, ast::List};
+    /// use orgize::{Org, ParseConfig, ast::List};
     ///
     /// let list = Org::parse("+ 1").first_node::<List>().unwrap();
     /// assert!(!list.is_ordered());
@@ -15,6 +28,10 @@ impl List {
     ///
     /// let list = Org::parse("1) 1\n- 2\n3. 3").first_node::<List>().unwrap();
     /// assert!(list.is_ordered());
+    ///
+    /// let config = ParseConfig { alphabetical_lists: true, ..Default::default() };
+    /// let list = config.parse("A) 1").first_node::<List>().unwrap();
+    /// assert!(list.is_ordered());
     /// ```
     pub fn is_ordered(&self) -> bool {
         self.items().next().map_or_else(
@@ -22,7 +39,10 @@ impl List {
                 debug_assert!(false, "list muts contains LIST_ITEM");
                 false
             },
-            |item| item.bullet().starts_with(|c: char| c.is_ascii_digit()),
+            |item| {
+                item.bullet()
+                    .starts_with(|c: char| c.is_ascii_alphanumeric())
+            },
         )
     }
 
@@ -49,6 +69,124 @@ impl List {
             },
         )
     }
+
+    /// Recomputes statistics cookies of items in this list, and returns
+    /// the updated raw text of this list
+    ///
+    /// Each `[n/m]` or `[p%]` cookie on an item is set from the checkboxes of
+    /// its direct sub-items, like `org-update-checkbox-count` does. If
+    /// `propagate_checkbox` is `true`, the checkbox of a parent item is also
+    /// updated: `[X]` when all sub-items are checked, `[ ]` when none is
+    /// and `[-]` otherwise.
+    ///
+    /// The returned string can be written back with [`Org::replace_range`].
+    ///
+    /// [`Org::replace_range`]: crate::Org::replace_range
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::List};
+    ///
+    /// let mut org = Org::parse(r#"- [ ] tasks [0/0] [0%]
+    ///   - [X] a
+    ///   - [ ] b [/]
+    ///     - [X] b.1
+    ///     - [X] b.2
+    ///   - c
+    /// "#);
+    ///
+    /// let list = org.first_node::<List>().unwrap();
+    /// assert_eq!(
+    ///     list.update_cookies(false),
+    ///     r#"- [ ] tasks [1/2] [50%]
+    ///   - [X] a
+    ///   - [ ] b [2/2]
+    ///     - [X] b.1
+    ///     - [X] b.2
+    ///   - c
+    /// "#
+    /// );
+    ///
+    /// let updated = list.update_cookies(true);
+    /// assert_eq!(
+    ///     updated,
+    ///     r#"- [X] tasks [2/2] [100%]
+    ///   - [X] a
+    ///   - [X] b [2/2]
+    ///     - [X] b.1
+    ///     - [X] b.2
+    ///   - c
+    /// "#
+    /// );
+    ///
+    /// org.replace_range(list.text_range(), updated);
+    /// assert!(org.to_org().starts_with("- [X] tasks [2/2] [100%]"));
+    /// ```
+    pub fn update_cookies(&self, propagate_checkbox: bool) -> String {
+        let mut edits = vec![];
+        for item in self.items() {
+            update_item_cookies(&item, propagate_checkbox, &mut edits);
+        }
+
+        let start = self.start();
+        let mut text = self.syntax.to_string();
+        edits.sort_by_key(|(range, _)| range.start());
+        for (range, replace_with) in edits.into_iter().rev() {
+            let range = range - start;
+            text.replace_range(
+                usize::from(range.start())..usize::from(range.end()),
+                &replace_with,
+            );
+        }
+        text
+    }
+}
+
+/// Collects cookie and checkbox edits for `item` and its descendants,
+/// returning the checkbox state of `item` after update
+fn update_item_cookies(
+    item: &ListItem,
+    propagate_checkbox: bool,
+    edits: &mut Vec<(TextRange, String)>,
+) -> Option<char> {
+    let states: Vec<char> = item
+        .sub_lists()
+        .flat_map(|list| list.items())
+        .filter_map(|child| update_item_cookies(&child, propagate_checkbox, edits))
+        .collect();
+
+    let checkbox = item.checkbox();
+    let mut state = checkbox.as_ref().and_then(|c| c.chars().next());
+
+    if states.is_empty() {
+        return state;
+    }
+
+    let total = states.len();
+    let done = states.iter().filter(|&&c| c == 'X').count();
+
+    for cookie in item.cookies() {
+        let value = if cookie.is_percent() {
+            format!("[{}%]", done * 100 / total)
+        } else {
+            format!("[{done}/{total}]")
+        };
+
+        edits.push((cookie.text_range(), value));
+    }
+
+    if let (true, Some(checkbox)) = (propagate_checkbox, checkbox) {
+        let new_state = if done == total {
+            'X'
+        } else if done == 0 && states.iter().all(|&c| c == ' ') {
+            ' '
+        } else {
+            '-'
+        };
+        edits.push((checkbox.text_range(), new_state.to_string()));
+        state = Some(new_state);
+    }
+
+    state
 }
 
 impl ListItem {
@@ -108,6 +246,32 @@ impl ListItem {
             })
     }
 
+    /// Returns the state of checkbox in this item
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{CheckboxState, List}};
+    ///
+    /// let org = Org::parse("- [X] a\n- [-] b\n- [ ] c\n- d");
+    /// let list = org.first_node::<List>().unwrap();
+    /// let states: Vec<_> = list.items().map(|item| item.checkbox_state()).collect();
+    /// assert_eq!(
+    ///     states,
+    ///     vec![
+    ///         Some(CheckboxState::Checked),
+    ///         Some(CheckboxState::Partial),
+    ///         Some(CheckboxState::Empty),
+    ///         None,
+    ///     ]
+    /// );
+    /// ```
+    pub fn checkbox_state(&self) -> Option<CheckboxState> {
+        self.checkbox().map(|c| match c.as_ref() {
+            "X" | "x" => CheckboxState::Checked,
+            "-" => CheckboxState::Partial,
+            _ => CheckboxState::Empty,
+        })
+    }
+
     pub fn counter(&self) -> Option<Token> {
         self.syntax
             .children()
@@ -136,4 +300,77 @@ impl ListItem {
                 })
             })
     }
+
+    /// Returns the raw text of tag in this item, without trailing whitespace
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{List, ListItem}};
+    ///
+    /// let org = Org::parse("- Emacs :: an editor\n- *Vim* :: another editor");
+    /// let list = org.first_node::<List>().unwrap();
+    /// assert!(list.is_descriptive());
+    /// let tags: Vec<_> = list.items().filter_map(|item| item.tag_raw()).collect();
+    /// assert_eq!(tags, vec!["Emacs", "*Vim*"]);
+    ///
+    /// let item = Org::parse("- no tag").first_node::<ListItem>().unwrap();
+    /// assert!(item.tag_raw().is_none());
+    /// ```
+    pub fn tag_raw(&self) -> Option<String> {
+        self.syntax
+            .children()
+            .find(|n| n.kind() == SyntaxKind::LIST_ITEM_TAG)
+            .map(|_| self.tag().map(|n| n.to_string()).collect::<String>())
+            .map(|tag| tag.trim_end().to_string())
+    }
+
+    /// Lists nested directly in this item
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::ListItem};
+    ///
+    /// let org = Org::parse("- a\n  - b\n    - c\n  - d\n- e");
+    /// let item = org.first_node::<ListItem>().unwrap();
+    /// let sub_items: Vec<_> = item
+    ///     .sub_lists()
+    ///     .flat_map(|list| list.items())
+    ///     .map(|item| item.raw())
+    ///     .collect();
+    /// assert_eq!(sub_items, vec!["  - b\n    - c\n", "  - d\n"]);
+    /// ```
+    pub fn sub_lists(&self) -> impl Iterator<Item = List> {
+        self.content()
+            .into_iter()
+            .flat_map(|n| n.children().filter_map(List::cast))
+    }
+
+    /// Cookies in the tag and the first paragraph of this item
+    fn cookies(&self) -> impl Iterator<Item = Cookie> {
+        let tag = self
+            .syntax
+            .children()
+            .find(|n| n.kind() == SyntaxKind::LIST_ITEM_TAG);
+
+        let paragraph = self
+            .content()
+            .and_then(|n| n.first_child())
+            .filter(|n| n.kind() == SyntaxKind::PARAGRAPH);
+
+        tag.into_iter()
+            .chain(paragraph)
+            .flat_map(|n| n.descendants().filter_map(Cookie::cast))
+    }
+
+    /// Returns the content of this item, including paragraphs and nested lists
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::ListItem};
+    ///
+    /// let item = Org::parse("- [ ] a\n  b").first_node::<ListItem>().unwrap();
+    /// assert_eq!(item.content().unwrap().to_string(), "a\n  b");
+    /// ```
+    pub fn content(&self) -> Option<SyntaxNode> {
+        self.syntax
+            .children()
+            .find(|n| n.kind() == SyntaxKind::LIST_ITEM_CONTENT)
+    }
 }
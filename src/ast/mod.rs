@@ -6,29 +6,49 @@ mod clock;
 #[cfg(feature = "syntax-org-fc")]
 mod cloze;
 mod comment;
+mod cookie;
 mod document;
 mod drawer;
 mod entity;
 mod fixed_width;
+mod fold;
+mod footnote;
 mod headline;
 mod inline_call;
 mod inline_src;
 mod keyword;
+mod latex;
 mod link;
 mod list;
 mod macros;
+mod paragraph;
 mod planning;
+mod section;
 mod snippet;
+mod subscript_superscript;
 mod table;
 mod timestamp;
 
+pub use block::{
+    BlockSwitches, NumberLines, ResultsCollection, ResultsFormat, ResultsHandling, ResultsParams,
+    ResultsType,
+};
+pub use clock::ClockGrouping;
 #[cfg(feature = "syntax-org-fc")]
 pub use cloze::*;
+pub use cookie::CookieValue;
+pub use fold::{FoldSummary, StartupVisibility};
 pub use generated::*;
 pub use headline::*;
+pub use latex::LatexDelimiter;
+pub use list::CheckboxState;
 pub use rowan::ast::support::*;
+pub use table::{ColumnAlign, TableFormula};
 pub use timestamp::*;
 
+pub(crate) use paragraph::push_plain_text;
+pub(crate) use table::cell_text;
+
 use crate::{
     syntax::{SyntaxKind, SyntaxNode},
     SyntaxToken,
@@ -174,3 +194,79 @@ impl fmt::Display for Token {
         fmt::Display::fmt(self.0.text(), f)
     }
 }
+
+/// Source span and raw text, shared by all AST nodes and [`Token`]
+///
+/// AST nodes already have inherent methods with the same names. This trait
+/// lets generic code, like diagnostics or diffing, treat any node the same.
+///
+/// ```rust
+/// use orgize::{Org, ast::{Headline, Link, Spanned, Timestamp}};
+///
+/// fn describe(node: &impl Spanned) -> String {
+///     format!("{:?}: {}", node.text_range(), node.raw())
+/// }
+///
+/// let org = Org::parse("* TODO a <2024-01-01 Mon>\n[[https://example.com]]");
+/// let hdl = org.first_node::<Headline>().unwrap();
+/// assert_eq!(describe(&hdl.todo_keyword().unwrap()), "2..6: TODO");
+/// assert_eq!(describe(&org.first_node::<Timestamp>().unwrap()), "9..25: <2024-01-01 Mon>");
+/// assert_eq!(describe(&org.first_node::<Link>().unwrap()), "26..49: [[https://example.com]]");
+/// ```
+pub trait Spanned {
+    /// Range of this node
+    fn text_range(&self) -> TextRange;
+
+    /// Raw text of this node
+    fn raw(&self) -> String;
+
+    /// Beginning position of this node
+    fn start(&self) -> TextSize {
+        self.text_range().start()
+    }
+
+    /// Ending position of this node
+    fn end(&self) -> TextSize {
+        self.text_range().end()
+    }
+
+    /// Returns 0-based line and column of the beginning of this node, see
+    /// [`Org::line_column`](crate::Org::line_column)
+    ///
+    /// `org` must be the document this node belongs to.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{Headline, Spanned, Timestamp}};
+    ///
+    /// let org = Org::parse("* a\r\n* b\r\n  <2024-01-01 Mon>\n");
+    /// let headlines: Vec<_> = org.headlines().collect();
+    /// assert_eq!(headlines[0].line_column(&org), (0, 0));
+    /// assert_eq!(headlines[1].line_column(&org), (1, 0));
+    ///
+    /// let ts = org.first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.line_column(&org), (2, 2));
+    /// ```
+    fn line_column(&self, org: &crate::Org) -> (u32, u32) {
+        org.line_column(self.start())
+    }
+}
+
+impl<N: AstNode<Language = crate::syntax::OrgLanguage>> Spanned for N {
+    fn text_range(&self) -> TextRange {
+        self.syntax().text_range()
+    }
+
+    fn raw(&self) -> String {
+        self.syntax().to_string()
+    }
+}
+
+impl Spanned for Token {
+    fn text_range(&self) -> TextRange {
+        self.0.text_range()
+    }
+
+    fn raw(&self) -> String {
+        self.0.text().to_string()
+    }
+}
@@ -0,0 +1,99 @@
+use rowan::{ast::AstNode, NodeOrToken};
+
+use super::{Entity, Link, Paragraph};
+use crate::{syntax::SyntaxKind, SyntaxElement};
+
+impl Paragraph {
+    /// Returns an iterator of objects in this paragraph
+    ///
+    /// Affiliated keywords and trailing blank lines are not included.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Paragraph, SyntaxKind};
+    ///
+    /// let org = Org::parse("#+CAPTION: c\nabc *abc* [[https://example.com][link]]\n\n");
+    /// let paragraph = org.first_node::<Paragraph>().unwrap();
+    /// let objects = paragraph.objects().collect::<Vec<_>>();
+    /// assert_eq!(objects.len(), 5);
+    /// assert_eq!(objects[0].kind(), SyntaxKind::TEXT);
+    /// assert_eq!(objects[1].kind(), SyntaxKind::BOLD);
+    /// assert_eq!(objects[1].to_string(), "*abc*");
+    /// assert_eq!(objects[3].kind(), SyntaxKind::LINK);
+    /// ```
+    pub fn objects(&self) -> impl Iterator<Item = SyntaxElement> {
+        self.syntax.children_with_tokens().filter(|e| {
+            e.kind() != SyntaxKind::AFFILIATED_KEYWORD && e.kind() != SyntaxKind::BLANK_LINE
+        })
+    }
+
+    /// Returns plain text of this paragraph, with markup removed
+    ///
+    /// Links are replaced by their description, or by their path if they
+    /// don't have one. Entities are rendered as UTF-8 and export snippets
+    /// are dropped. Other objects keep their raw text.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Paragraph};
+    ///
+    /// let org = Org::parse("*bold* and /it/ \\alpha [[https://a.com][*site*]] [[https://b.com]] @@html:<br>@@");
+    /// let paragraph = org.first_node::<Paragraph>().unwrap();
+    /// assert_eq!(paragraph.text(), "bold and it α site https://b.com ");
+    ///
+    /// let org = Org::parse("line\\\\\nbreak <2024-01-01 Mon>\n\n");
+    /// let paragraph = org.first_node::<Paragraph>().unwrap();
+    /// assert_eq!(paragraph.text(), "line\nbreak <2024-01-01 Mon>\n");
+    /// ```
+    pub fn text(&self) -> String {
+        let mut s = String::new();
+        for object in self.objects() {
+            push_plain_text(&mut s, object);
+        }
+        s
+    }
+}
+
+pub(crate) fn push_plain_text(s: &mut String, element: SyntaxElement) {
+    match element {
+        NodeOrToken::Token(token) => match token.kind() {
+            SyntaxKind::TEXT | SyntaxKind::WHITESPACE | SyntaxKind::NEW_LINE => {
+                s.push_str(token.text())
+            }
+            _ => {}
+        },
+        NodeOrToken::Node(node) => match node.kind() {
+            SyntaxKind::BOLD
+            | SyntaxKind::ITALIC
+            | SyntaxKind::UNDERLINE
+            | SyntaxKind::STRIKE
+            | SyntaxKind::VERBATIM
+            | SyntaxKind::CODE
+            | SyntaxKind::SUPERSCRIPT
+            | SyntaxKind::SUBSCRIPT
+            | SyntaxKind::TARGET
+            | SyntaxKind::RADIO_TARGET
+            | SyntaxKind::LINE_BREAK => {
+                for child in node.children_with_tokens() {
+                    push_plain_text(s, child);
+                }
+            }
+            SyntaxKind::LINK => {
+                if let Some(link) = Link::cast(node) {
+                    if link.has_description() {
+                        for child in link.description() {
+                            push_plain_text(s, child);
+                        }
+                    } else {
+                        s.push_str(&link.path());
+                    }
+                }
+            }
+            SyntaxKind::ENTITY => {
+                if let Some(entity) = Entity::cast(node) {
+                    s.push_str(entity.utf8());
+                }
+            }
+            SyntaxKind::SNIPPET => {}
+            _ => s.push_str(&node.to_string()),
+        },
+    }
+}
@@ -0,0 +1,37 @@
+use rowan::ast::AstNode;
+
+use super::{Drawer, Section};
+
+impl Section {
+    /// Returns `true` if this section contains only blank lines
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Section};
+    ///
+    /// let section = Org::parse("* a\n\n  \n* b").first_node::<Section>().unwrap();
+    /// assert!(section.is_empty());
+    /// let section = Org::parse("* a\n\ntext").first_node::<Section>().unwrap();
+    /// assert!(!section.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.syntax
+            .text()
+            .try_for_each_chunk(|chunk| {
+                if chunk.trim().is_empty() {
+                    Ok(())
+                } else {
+                    Err(())
+                }
+            })
+            .is_ok()
+    }
+
+    /// Returns `true` if this section contains anything besides blank lines
+    /// and `LOGBOOK` drawers
+    pub(crate) fn has_content(&self) -> bool {
+        self.syntax.children().any(|node| {
+            !node.text().to_string().trim().is_empty()
+                && !Drawer::cast(node).is_some_and(|d| d.name().eq_ignore_ascii_case("LOGBOOK"))
+        })
+    }
+}
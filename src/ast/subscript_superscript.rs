@@ -0,0 +1,87 @@
+use crate::{SyntaxKind, SyntaxNode};
+
+use super::{Subscript, Superscript};
+
+impl Superscript {
+    /// Returns the raw content of this superscript, without braces
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Superscript};
+    ///
+    /// let sup = Org::parse("x^{2}").first_node::<Superscript>().unwrap();
+    /// assert_eq!(sup.value(), "2");
+    /// let sup = Org::parse("e^{i *pi*}").first_node::<Superscript>().unwrap();
+    /// assert_eq!(sup.value(), "i *pi*");
+    /// let sup = Org::parse("10^-3").first_node::<Superscript>().unwrap();
+    /// assert_eq!(sup.value(), "-3");
+    ///
+    /// // whitespace after caret
+    /// assert!(Org::parse("a^ b").first_node::<Superscript>().is_none());
+    /// ```
+    pub fn value(&self) -> String {
+        script_value(&self.syntax)
+    }
+
+    /// Returns `true` if this superscript is wrapped in braces, like `x^{2}`
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Superscript};
+    ///
+    /// assert!(Org::parse("x^{2}").first_node::<Superscript>().unwrap().is_braced());
+    /// assert!(!Org::parse("x^2").first_node::<Superscript>().unwrap().is_braced());
+    /// ```
+    pub fn is_braced(&self) -> bool {
+        is_braced(&self.syntax)
+    }
+}
+
+impl Subscript {
+    /// Returns the raw content of this subscript, without braces
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Subscript};
+    ///
+    /// let sub = Org::parse("a_1").first_node::<Subscript>().unwrap();
+    /// assert_eq!(sub.value(), "1");
+    /// let sub = Org::parse("H_{2}O").first_node::<Subscript>().unwrap();
+    /// assert_eq!(sub.value(), "2");
+    ///
+    /// // whitespace after underscore
+    /// assert!(Org::parse("a_ b").first_node::<Subscript>().is_none());
+    /// ```
+    pub fn value(&self) -> String {
+        script_value(&self.syntax)
+    }
+
+    /// Returns `true` if this subscript is wrapped in braces, like `H_{2}O`
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Subscript};
+    ///
+    /// assert!(Org::parse("H_{2}O").first_node::<Subscript>().unwrap().is_braced());
+    /// assert!(!Org::parse("a_1").first_node::<Subscript>().unwrap().is_braced());
+    /// ```
+    pub fn is_braced(&self) -> bool {
+        is_braced(&self.syntax)
+    }
+}
+
+fn script_value(node: &SyntaxNode) -> String {
+    node.children_with_tokens()
+        .filter(|e| {
+            !matches!(
+                e.kind(),
+                SyntaxKind::CARET
+                    | SyntaxKind::UNDERSCORE
+                    | SyntaxKind::L_CURLY
+                    | SyntaxKind::R_CURLY
+            )
+        })
+        .map(|e| e.to_string())
+        .collect()
+}
+
+fn is_braced(node: &SyntaxNode) -> bool {
+    node.children_with_tokens()
+        .any(|e| e.kind() == SyntaxKind::L_CURLY)
+}
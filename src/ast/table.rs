@@ -1,9 +1,39 @@
+use std::borrow::Cow;
+
 use rowan::ast::AstNode;
 
-use super::{filter_token, OrgTable, OrgTableRow, Token};
-use crate::syntax::SyntaxKind;
+use super::{filter_token, OrgTable, OrgTableCell, OrgTableRow, Token};
+use crate::{syntax::SyntaxKind, width::display_width};
 
 impl OrgTable {
+    /// Rows of this table, including rules
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::OrgTable};
+    ///
+    /// let org = Org::parse(r#"
+    /// | name | age |
+    /// |------+-----|
+    /// | alice | 20 |
+    /// | bob   |    |"#);
+    /// let table = org.first_node::<OrgTable>().unwrap();
+    ///
+    /// let rows: Vec<_> = table
+    ///     .rows()
+    ///     .filter(|row| row.is_standard())
+    ///     .map(|row| {
+    ///         row.cells()
+    ///             .map(|cell| cell.map(|c| c.raw()).unwrap_or_default())
+    ///             .collect::<Vec<_>>()
+    ///     })
+    ///     .collect();
+    /// assert_eq!(rows, vec![vec!["name", "age"], vec!["alice", "20"], vec!["bob", ""]]);
+    /// assert_eq!(table.rows().filter(|row| row.is_rule()).count(), 1);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = OrgTableRow> {
+        self.syntax.children().filter_map(OrgTableRow::cast)
+    }
+
     /// Returns `true` if this table has a header
     ///
     /// A table has a header when it contains at least two row groups.
@@ -41,14 +71,48 @@ impl OrgTable {
     /// assert!(!table.has_header());
     /// ```
     pub fn has_header(&self) -> bool {
-        self.syntax
-            .children()
-            .filter_map(OrgTableRow::cast)
+        self.rows()
             .skip_while(|row| row.is_rule())
             .skip_while(|row| row.is_standard())
             .any(|row| !row.is_rule())
     }
 
+    /// Returns alignment of each column, from the last row of alignment
+    /// cookies
+    ///
+    /// Columns without an alignment cookie are `None`, and all columns are
+    /// `None` if the table has no such row.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{ColumnAlign, OrgTable}};
+    ///
+    /// let org = Org::parse(r#"
+    /// |     | <r> |     |
+    /// | a   | 1   | x   |
+    /// | bb  | 22  | yy  |"#);
+    /// let table = org.first_node::<OrgTable>().unwrap();
+    /// assert_eq!(table.column_alignments(), vec![None, Some(ColumnAlign::Right), None]);
+    ///
+    /// let org = Org::parse("| a | b |\n| c | d |");
+    /// let table = org.first_node::<OrgTable>().unwrap();
+    /// assert_eq!(table.column_alignments(), vec![None, None]);
+    /// ```
+    pub fn column_alignments(&self) -> Vec<Option<ColumnAlign>> {
+        let columns = self
+            .rows()
+            .map(|row| row.cells().count())
+            .max()
+            .unwrap_or_default();
+
+        let mut alignments = self
+            .rows()
+            .filter_map(|row| row.alignments())
+            .last()
+            .unwrap_or_default();
+        alignments.resize(columns, None);
+        alignments
+    }
+
     /// Formulas associated to the table
     ///
     /// ```rust
@@ -79,6 +143,144 @@ impl OrgTable {
             }
         })
     }
+
+    /// Returns formulas in `#+TBLFM` lines of this table
+    ///
+    /// Formulas in one line are separated by `::`. They are not evaluated.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{OrgTable, TableFormula}};
+    ///
+    /// let org = Org::parse("| 1 | 2 |   |\n#+TBLFM: $3=$1+$2;%.1f::@1$1=5\n#+TBLFM: $2='(+ 1 2)");
+    /// let formulas = org.first_node::<OrgTable>().unwrap().formulas();
+    /// assert_eq!(formulas.len(), 3);
+    /// assert_eq!(formulas[0], TableFormula {
+    ///     target: "$3".into(),
+    ///     expression: "$1+$2".into(),
+    ///     format: Some("%.1f".into()),
+    /// });
+    /// assert!(formulas[0].is_column());
+    /// assert_eq!(formulas[1].target, "@1$1");
+    /// assert!(!formulas[1].is_column());
+    /// assert_eq!(formulas[2].expression, "'(+ 1 2)");
+    /// assert_eq!(formulas[2].format, None);
+    ///
+    /// // `#+TBLFM` lines not following a table are keywords
+    /// assert!(Org::parse("#+TBLFM: $3=$1").first_node::<OrgTable>().is_none());
+    /// ```
+    pub fn formulas(&self) -> Vec<TableFormula> {
+        self.tblfm()
+            .flat_map(|line| {
+                line.split("::")
+                    .filter_map(TableFormula::parse)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Returns the table text with columns aligned
+    ///
+    /// Cells are padded to the display width of the widest cell in their
+    /// column, and rules are redrawn to match. The indentation of the first
+    /// row is used for all rows, and affiliated keywords, formulas and blank
+    /// lines are kept as is.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::OrgTable};
+    ///
+    /// let org = Org::parse("  |name|qty|\n|-\n| 日本語 | 2|\n|x|\n#+TBLFM: $2=1\n");
+    /// let table = org.first_node::<OrgTable>().unwrap();
+    /// assert_eq!(
+    ///     table.align(),
+    ///     "  | name   | qty |\n  |--------+-----|\n  | 日本語 | 2   |\n  | x      |     |\n#+TBLFM: $2=1\n"
+    /// );
+    ///
+    /// // escaped vertical bars don't split cells
+    /// let table = Org::parse("|a\\|b|c|\n|d|").first_node::<OrgTable>().unwrap();
+    /// assert_eq!(table.align(), "| a\\|b | c |\n| d    |   |");
+    /// ```
+    pub fn align(&self) -> String {
+        let rows = self
+            .syntax
+            .children()
+            .filter_map(OrgTableRow::cast)
+            .map(|row| {
+                let text = row.syntax.to_string();
+                let cells = if row.is_rule() {
+                    None
+                } else {
+                    let mut cells = split_cells(text.trim().trim_start_matches('|'))
+                        .map(|cell| cell.trim().to_string())
+                        .collect::<Vec<_>>();
+                    if text.trim_end().ends_with('|') {
+                        cells.pop();
+                    }
+                    Some(cells)
+                };
+                (text, cells)
+            })
+            .collect::<Vec<_>>();
+
+        let Some((first, _)) = rows.first() else {
+            return self.syntax.to_string();
+        };
+        let indent = &first[..first.len() - first.trim_start_matches([' ', '\t']).len()];
+
+        let mut widths: Vec<usize> = vec![];
+        for cells in rows.iter().filter_map(|(_, cells)| cells.as_ref()) {
+            for (idx, cell) in cells.iter().enumerate() {
+                let width = display_width(cell).max(1);
+                match widths.get_mut(idx) {
+                    Some(w) => *w = (*w).max(width),
+                    None => widths.push(width),
+                }
+            }
+        }
+
+        let mut output = String::new();
+        for element in self.syntax.children_with_tokens() {
+            if element.kind() != SyntaxKind::AFFILIATED_KEYWORD {
+                break;
+            }
+            output.push_str(&element.to_string());
+        }
+
+        for (text, cells) in &rows {
+            output.push_str(indent);
+            match cells {
+                Some(cells) => {
+                    output.push('|');
+                    for (idx, width) in widths.iter().enumerate() {
+                        let cell = cells.get(idx).map(String::as_str).unwrap_or_default();
+                        output.push(' ');
+                        output.push_str(cell);
+                        output.push_str(&" ".repeat(width - display_width(cell) + 1));
+                        output.push('|');
+                    }
+                }
+                None => {
+                    output.push('|');
+                    let rule = widths
+                        .iter()
+                        .map(|width| "-".repeat(width + 2))
+                        .collect::<Vec<_>>();
+                    output.push_str(&rule.join("+"));
+                    output.push('|');
+                }
+            }
+            output.push_str(&text[text.trim_end_matches(['\r', '\n']).len()..]);
+        }
+
+        for element in self.syntax.children_with_tokens() {
+            if !OrgTableRow::can_cast(element.kind())
+                && element.kind() != SyntaxKind::AFFILIATED_KEYWORD
+            {
+                output.push_str(&element.to_string());
+            }
+        }
+
+        output
+    }
 }
 
 impl OrgTableRow {
@@ -107,4 +309,187 @@ impl OrgTableRow {
     pub fn is_standard(&self) -> bool {
         self.syntax.kind() == SyntaxKind::ORG_TABLE_STANDARD_ROW
     }
+
+    /// Returns alignments of each column if this row only contains
+    /// alignment cookies like `<l>`, `<c10>` or `<r>`
+    ///
+    /// Empty cells and width-only cookies like `<10>` are `None`. Rows with
+    /// other content return `None`.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{ColumnAlign, OrgTableRow}};
+    ///
+    /// let org = Org::parse("| <l> | <10> | | <c5> |");
+    /// let row = org.first_node::<OrgTableRow>().unwrap();
+    /// assert_eq!(
+    ///     row.alignments(),
+    ///     Some(vec![Some(ColumnAlign::Left), None, None, Some(ColumnAlign::Center)])
+    /// );
+    ///
+    /// let org = Org::parse("| <l> | a |");
+    /// let row = org.first_node::<OrgTableRow>().unwrap();
+    /// assert_eq!(row.alignments(), None);
+    /// ```
+    pub fn alignments(&self) -> Option<Vec<Option<ColumnAlign>>> {
+        let cells: Vec<_> = self.cells().collect();
+        if cells.iter().all(Option::is_none) {
+            return None;
+        }
+
+        cells
+            .iter()
+            .map(|cell| match cell {
+                Some(cell) => ColumnAlign::parse_cookie(&cell.raw()),
+                None => Some(None),
+            })
+            .collect()
+    }
+
+    /// Returns cells of this row
+    ///
+    /// Empty cells are returned as `None`, so that cells in the same column
+    /// share the same index. Rules have no cells.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::OrgTableRow};
+    ///
+    /// let org = Org::parse("| a |  | c|");
+    /// let row = org.first_node::<OrgTableRow>().unwrap();
+    /// let cells: Vec<_> = row.cells().map(|c| c.map(|c| c.raw())).collect();
+    /// assert_eq!(cells, vec![Some("a".into()), None, Some("c".into())]);
+    ///
+    /// let org = Org::parse("|---+---|");
+    /// let row = org.first_node::<OrgTableRow>().unwrap();
+    /// assert_eq!(row.cells().count(), 0);
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = Option<OrgTableCell>> {
+        let mut cells = vec![];
+        let mut has_content = false;
+
+        for elem in self.syntax.children_with_tokens() {
+            match elem.kind() {
+                SyntaxKind::PIPE => {
+                    cells.push(None);
+                    has_content = false;
+                }
+                SyntaxKind::ORG_TABLE_CELL => {
+                    if let Some(last) = cells.last_mut() {
+                        *last = elem.into_node().and_then(OrgTableCell::cast);
+                        has_content = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // trailing pipe doesn't start a new cell
+        if !has_content && self.syntax.text().to_string().trim_end().ends_with('|') {
+            cells.pop();
+        }
+
+        cells.into_iter()
+    }
+}
+
+/// Alignment of a table column, returned by [`OrgTable::column_alignments`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnAlign {
+    /// `<l>`
+    Left,
+    /// `<c>`
+    Center,
+    /// `<r>`
+    Right,
+}
+
+impl ColumnAlign {
+    /// Parses an alignment cookie, returns `Some(None)` for width-only
+    /// cookies like `<10>`
+    fn parse_cookie(s: &str) -> Option<Option<ColumnAlign>> {
+        let cookie = s.trim().strip_prefix('<')?.strip_suffix('>')?;
+        let (alignment, width) = match cookie.as_bytes().first() {
+            Some(b'l' | b'c' | b'r') => cookie.split_at(1),
+            _ => ("", cookie),
+        };
+        if !width.bytes().all(|b| b.is_ascii_digit()) || (alignment.is_empty() && width.is_empty())
+        {
+            return None;
+        }
+        Some(match alignment {
+            "l" => Some(ColumnAlign::Left),
+            "c" => Some(ColumnAlign::Center),
+            "r" => Some(ColumnAlign::Right),
+            _ => None,
+        })
+    }
+}
+
+/// A formula in `#+TBLFM` lines, returned by [`OrgTable::formulas`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableFormula {
+    /// Reference to the column or field the formula applies to, like `$3`
+    /// or `@2$1`
+    pub target: String,
+    /// Formula itself, without format flags
+    pub expression: String,
+    /// Format flags after the last `;`, like `%.2f` or `N`
+    pub format: Option<String>,
+}
+
+impl TableFormula {
+    fn parse(s: &str) -> Option<TableFormula> {
+        let (target, rest) = s.trim().split_once('=')?;
+        let (expression, format) = match rest.rsplit_once(';') {
+            Some((expression, format)) => (expression, Some(format.trim().to_string())),
+            None => (rest, None),
+        };
+        Some(TableFormula {
+            target: target.trim().to_string(),
+            expression: expression.trim().to_string(),
+            format,
+        })
+    }
+
+    /// Returns `true` if this is a column formula, i.e. its target is a
+    /// column reference like `$3`
+    pub fn is_column(&self) -> bool {
+        self.target.starts_with('$') && !self.target.contains('@')
+    }
+}
+
+/// Splits a row at vertical bars, except escaped ones
+fn split_cells(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(s);
+    std::iter::from_fn(move || {
+        let s = rest?;
+        let bytes = s.as_bytes();
+        let mut idx = 0;
+        while idx < bytes.len() {
+            match bytes[idx] {
+                b'|' => {
+                    rest = Some(&s[idx + 1..]);
+                    return Some(&s[..idx]);
+                }
+                b'\\' if bytes.get(idx + 1) == Some(&b'|') => idx += 2,
+                _ => idx += 1,
+            }
+        }
+        rest = None;
+        Some(s)
+    })
+}
+
+/// Returns text of given token, with `\|` unescaped if it's inside a
+/// table cell
+pub(crate) fn cell_text(text: &Token) -> Cow<'_, str> {
+    if text.contains("\\|")
+        && text
+            .syntax()
+            .parent_ancestors()
+            .any(|n| n.kind() == SyntaxKind::ORG_TABLE_CELL)
+    {
+        Cow::Owned(text.replace("\\|", "|"))
+    } else {
+        Cow::Borrowed(text)
+    }
 }
@@ -1,5 +1,7 @@
-use super::{filter_token, Timestamp};
-use crate::syntax::SyntaxKind;
+use std::time::Duration;
+
+use super::{filter_token, Timestamp, Token};
+use crate::{syntax::SyntaxKind, ParseConfig};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TimeUnit {
@@ -23,6 +25,52 @@ pub enum DelayType {
     First,
 }
 
+/// Repeater of a timestamp, like `+1w`, returned by [`Timestamp::repeater`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Repeater {
+    pub kind: RepeaterType,
+    pub value: u32,
+    pub unit: TimeUnit,
+}
+
+/// Warning delay of a timestamp, like `-2d`, returned by
+/// [`Timestamp::warning`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Delay {
+    pub kind: DelayType,
+    pub value: u32,
+    pub unit: TimeUnit,
+}
+
+/// Controls how [`Timestamp::to_org_string`] renders a timestamp
+#[derive(Clone, Copy, Debug)]
+pub struct TimestampFormat {
+    /// Render an active (`<...>`) or inactive (`[...]`) timestamp
+    ///
+    /// Defaults to `None`, which keeps the original brackets.
+    pub active: Option<bool>,
+
+    /// Include the time of day, if the timestamp has one
+    ///
+    /// Defaults to `true`.
+    pub time: bool,
+
+    /// Include the abbreviated day name, computed from the date
+    ///
+    /// Defaults to `true`.
+    pub weekday: bool,
+}
+
+impl Default for TimestampFormat {
+    fn default() -> Self {
+        TimestampFormat {
+            active: None,
+            time: true,
+            weekday: true,
+        }
+    }
+}
+
 impl Timestamp {
     /// ```rust
     /// use orgize::{Org, ast::Timestamp};
@@ -168,6 +216,53 @@ impl Timestamp {
         self.nth_delay(0).map(|i| i.2)
     }
 
+    /// Returns the repeater of this timestamp
+    ///
+    /// Returns `None` if there's no repeater, or it's malformed.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{Headline, Repeater, RepeaterType, TimeUnit}};
+    ///
+    /// let org = Org::parse("* TODO a\nSCHEDULED: <2024-10-12 Sat .+1w -2d>");
+    /// let ts = org.first_node::<Headline>().unwrap().scheduled().unwrap();
+    /// assert_eq!(
+    ///     ts.repeater(),
+    ///     Some(Repeater { kind: RepeaterType::Restart, value: 1, unit: TimeUnit::Week })
+    /// );
+    ///
+    /// let org = Org::parse("* TODO a\nSCHEDULED: <2024-10-12 Sat>");
+    /// let ts = org.first_node::<Headline>().unwrap().scheduled().unwrap();
+    /// assert_eq!(ts.repeater(), None);
+    /// ```
+    pub fn repeater(&self) -> Option<Repeater> {
+        self.nth_repeater(0)
+            .map(|(kind, value, unit)| Repeater { kind, value, unit })
+    }
+
+    /// Returns the warning delay of this timestamp
+    ///
+    /// Returns `None` if there's no warning delay, or it's malformed.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{Delay, DelayType, Headline, TimeUnit}};
+    ///
+    /// let org = Org::parse("* TODO a\nDEADLINE: <2024-10-12 Sat ++1m --2d>");
+    /// let ts = org.first_node::<Headline>().unwrap().deadline().unwrap();
+    /// assert_eq!(
+    ///     ts.warning(),
+    ///     Some(Delay { kind: DelayType::First, value: 2, unit: TimeUnit::Day })
+    /// );
+    /// assert_eq!(ts.repeater().unwrap().value, 1);
+    ///
+    /// let org = Org::parse("* TODO a\nDEADLINE: <2024-10-12 Sat +1w>");
+    /// let ts = org.first_node::<Headline>().unwrap().deadline().unwrap();
+    /// assert_eq!(ts.warning(), None);
+    /// ```
+    pub fn warning(&self) -> Option<Delay> {
+        self.nth_delay(0)
+            .map(|(kind, value, unit)| Delay { kind, value, unit })
+    }
+
     fn nth_repeater(&self, nth: usize) -> Option<(RepeaterType, u32, TimeUnit)> {
         let mut i = nth + 1;
 
@@ -233,6 +328,342 @@ impl Timestamp {
         Some((mark, value, unit))
     }
 
+    /// Returns the span between the start and the end of this timestamp
+    ///
+    /// Both the `HH:MM-HH:MM` form and the `<start>--<end>` form are
+    /// supported.
+    ///
+    /// Returns `None` if this timestamp isn't a range, either end has no
+    /// clock time, it's a diary timestamp, its date is invalid, or the end
+    /// is before the start.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Timestamp};
+    /// use std::time::Duration;
+    ///
+    /// let ts = Org::parse("<2024-01-01 Mon 10:00-12:30>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.duration(), Some(Duration::from_secs(150 * 60)));
+    ///
+    /// let ts = Org::parse("<2024-02-28 Wed 23:00>--<2024-03-01 Fri 01:00>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.duration(), Some(Duration::from_secs(26 * 60 * 60)));
+    ///
+    /// // clocked headlines use the double-timestamp form
+    /// let org = Org::parse("* a\n:LOGBOOK:\nCLOCK: [2024-10-12 Sat 09:00]--[2024-10-12 Sat 11:30] =>  2:30\n:END:");
+    /// let ts = org.first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.duration(), Some(Duration::from_secs(150 * 60)));
+    ///
+    /// let ts = Org::parse("<2024-01-01 Mon 10:00>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.duration(), None);
+    ///
+    /// let ts = Org::parse("<2024-01-01>--<2024-01-03>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.duration(), None);
+    ///
+    /// let ts = Org::parse("<2024-01-01 Mon 10:00>--<2024-01-03 Wed>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.duration(), None);
+    ///
+    /// let ts = Org::parse("<2024-01-01 Mon 12:00-10:00>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.duration(), None);
+    ///
+    /// let ts = Org::parse("<%%(diary-float t 4 2)>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.duration(), None);
+    /// ```
+    pub fn duration(&self) -> Option<Duration> {
+        let hours = self
+            .syntax
+            .children_with_tokens()
+            .filter_map(filter_token(SyntaxKind::TIMESTAMP_HOUR))
+            .count();
+        // both ends must have a clock time
+        if !self.is_range() || hours < 2 {
+            return None;
+        }
+        let minutes = self.end_minutes()? - self.start_minutes()?;
+        u64::try_from(minutes)
+            .ok()
+            .map(|m| Duration::from_secs(m * 60))
+    }
+
+    /// Returns `true` if this timestamp and `other` share a moment in time
+    ///
+    /// Ranges that only touch at their boundaries, like `10:00-11:00` and
+    /// `11:00-12:00`, don't overlap. A timestamp which isn't a range overlaps
+    /// with a range containing it, including its boundaries.
+    ///
+    /// Returns `false` if either timestamp is a diary timestamp or invalid.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Timestamp};
+    ///
+    /// let ts = |s: &str| Org::parse(s).first_node::<Timestamp>().unwrap();
+    ///
+    /// let meeting = ts("<2024-01-01 Mon 10:00-12:00>");
+    /// assert!(meeting.overlaps(&ts("<2024-01-01 Mon 11:00-13:00>")));
+    /// assert!(meeting.overlaps(&ts("<2023-12-31 Sun>--<2024-01-02 Tue>")));
+    /// assert!(meeting.overlaps(&ts("<2024-01-01 Mon 12:00>")));
+    /// assert!(!meeting.overlaps(&ts("<2024-01-01 Mon 12:00-13:00>")));
+    /// assert!(!meeting.overlaps(&ts("<2024-01-02 Tue 10:00-12:00>")));
+    /// assert!(ts("<2024-01-01 Mon 10:00>").overlaps(&ts("<2024-01-01 Mon 10:00>")));
+    /// ```
+    pub fn overlaps(&self, other: &Timestamp) -> bool {
+        let (Some(a_start), Some(a_end), Some(b_start), Some(b_end)) = (
+            self.start_minutes(),
+            self.end_minutes(),
+            other.start_minutes(),
+            other.end_minutes(),
+        ) else {
+            return false;
+        };
+
+        if a_start == a_end || b_start == b_end {
+            a_start <= b_end && b_start <= a_end
+        } else {
+            a_start < b_end && b_start < a_end
+        }
+    }
+
+    /// Returns the day name written after the start date, if any
+    ///
+    /// The day name is returned as is, without being validated against the
+    /// date. Use [`Timestamp::expected_weekday`] to get the correct one.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Timestamp};
+    ///
+    /// let ts = Org::parse("<2024-01-01 Mon 10:00>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.weekday().unwrap(), "Mon");
+    /// let ts = Org::parse("<2024-01-01 Fri>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.weekday().unwrap(), "Fri");
+    /// let ts = Org::parse("<2024-01-01>").first_node::<Timestamp>().unwrap();
+    /// assert!(ts.weekday().is_none());
+    /// ```
+    pub fn weekday(&self) -> Option<Token> {
+        super::token(&self.syntax, SyntaxKind::TIMESTAMP_DAYNAME)
+    }
+
+    /// Returns the abbreviated English day name of the start date, computed
+    /// from the date itself
+    ///
+    /// Returns `None` for diary timestamps or invalid dates.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Timestamp};
+    ///
+    /// let ts = Org::parse("<2024-01-01 Fri>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.expected_weekday(), Some("Mon"));
+    /// let ts = Org::parse("[2000-02-29]").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.expected_weekday(), Some("Tue"));
+    /// let ts = Org::parse("[2023-02-29]").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.expected_weekday(), None);
+    /// ```
+    pub fn expected_weekday(&self) -> Option<&'static str> {
+        weekday_name(
+            self.year_start()?.parse().ok()?,
+            self.month_start()?.parse().ok()?,
+            self.day_start()?.parse().ok()?,
+        )
+    }
+
+    /// Returns the text of this timestamp with day names matching its dates
+    ///
+    /// Wrong day names are replaced and missing ones are inserted. Dates that
+    /// are invalid are left untouched. Replace the timestamp's range with
+    /// the returned text (e.g. using [`Org::replace_range`]) to apply it.
+    ///
+    /// [`Org::replace_range`]: crate::Org::replace_range
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::Timestamp};
+    ///
+    /// let ts = Org::parse("<2024-01-01 Fri 10:00 +1w>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.normalize_weekday(), "<2024-01-01 Mon 10:00 +1w>");
+    /// let ts = Org::parse("[2024-01-01]--[2024-01-03 Wed]").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.normalize_weekday(), "[2024-01-01 Mon]--[2024-01-03 Wed]");
+    /// let ts = Org::parse("<2024-13-01 Mon>").first_node::<Timestamp>().unwrap();
+    /// assert_eq!(ts.normalize_weekday(), "<2024-13-01 Mon>");
+    /// ```
+    pub fn normalize_weekday(&self) -> String {
+        let mut s = String::with_capacity(self.syntax.text().len().into());
+        let (mut year, mut month) = (None, None);
+        let mut weekday = None;
+
+        for token in self
+            .syntax
+            .children_with_tokens()
+            .filter_map(|e| e.into_token())
+        {
+            match token.kind() {
+                SyntaxKind::TIMESTAMP_YEAR => year = token.text().parse().ok(),
+                SyntaxKind::TIMESTAMP_MONTH => month = token.text().parse().ok(),
+                SyntaxKind::TIMESTAMP_DAY => {
+                    s.push_str(token.text());
+                    weekday = year
+                        .zip(month)
+                        .zip(token.text().parse().ok())
+                        .and_then(|((y, m), d)| weekday_name(y, m, d));
+                    let has_dayname = token
+                        .siblings_with_tokens(rowan::Direction::Next)
+                        .skip(1)
+                        .find(|e| e.kind() != SyntaxKind::WHITESPACE)
+                        .is_some_and(|e| e.kind() == SyntaxKind::TIMESTAMP_DAYNAME);
+                    if let (Some(weekday), false) = (weekday, has_dayname) {
+                        s.push(' ');
+                        s.push_str(weekday);
+                    }
+                    continue;
+                }
+                SyntaxKind::TIMESTAMP_DAYNAME => {
+                    s.push_str(weekday.unwrap_or(token.text()));
+                    continue;
+                }
+                _ => {}
+            }
+            s.push_str(token.text());
+        }
+
+        s
+    }
+
+    /// Renders this timestamp as org-mode text in canonical form
+    ///
+    /// Dates are written as `YYYY-MM-DD`, followed by the day name computed
+    /// from the date and times as `HH:MM`, all separated by single spaces.
+    /// Repeaters and warning delays are kept. If the date is invalid, the
+    /// original day name is kept.
+    ///
+    /// Diary timestamps are returned as is.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{Timestamp, TimestampFormat}};
+    ///
+    /// let ts = |s: &str| Org::parse(s).first_node::<Timestamp>().unwrap();
+    ///
+    /// let format = TimestampFormat::default();
+    /// assert_eq!(ts("<2024-01-01   10:00>").to_org_string(format), "<2024-01-01 Mon 10:00>");
+    /// assert_eq!(ts("<2024-01-01 Fri 10:00-12:30 +1w -2d>").to_org_string(format), "<2024-01-01 Mon 10:00-12:30 +1w -2d>");
+    /// assert_eq!(ts("[2024-01-01]--[2024-01-03]").to_org_string(format), "[2024-01-01 Mon]--[2024-01-03 Wed]");
+    ///
+    /// let format = TimestampFormat { active: Some(false), time: false, weekday: false };
+    /// assert_eq!(ts("<2024-01-01 Mon 10:00 .+1d>").to_org_string(format), "[2024-01-01 .+1d]");
+    ///
+    /// let format = TimestampFormat { active: Some(true), ..Default::default() };
+    /// assert_eq!(ts("[2024-01-01 Mon 10:00]--[2024-01-02 Tue 11:00]").to_org_string(format), "<2024-01-01 Mon 10:00>--<2024-01-02 Tue 11:00>");
+    /// assert_eq!(ts("<%%(diary-float t 4 2)>").to_org_string(format), "<%%(diary-float t 4 2)>");
+    /// ```
+    pub fn to_org_string(&self, format: TimestampFormat) -> String {
+        #[derive(Default)]
+        struct Part<'a> {
+            date: [&'a str; 3],
+            dayname: Option<&'a str>,
+            times: Vec<[&'a str; 2]>,
+            suffixes: Vec<String>,
+        }
+
+        if self.is_diary() {
+            return self.syntax.to_string();
+        }
+
+        let tokens = self
+            .syntax
+            .children_with_tokens()
+            .filter_map(|e| e.into_token())
+            .collect::<Vec<_>>();
+
+        let mut parts: Vec<Part> = vec![Part::default()];
+        for token in &tokens {
+            let part = parts.last_mut().unwrap();
+            let text = token.text();
+            match token.kind() {
+                SyntaxKind::MINUS2 => parts.push(Part::default()),
+                SyntaxKind::TIMESTAMP_YEAR => part.date[0] = text,
+                SyntaxKind::TIMESTAMP_MONTH => part.date[1] = text,
+                SyntaxKind::TIMESTAMP_DAY => part.date[2] = text,
+                SyntaxKind::TIMESTAMP_DAYNAME => part.dayname = Some(text),
+                SyntaxKind::TIMESTAMP_HOUR => part.times.push([text, ""]),
+                SyntaxKind::TIMESTAMP_MINUTE => {
+                    if let Some(time) = part.times.last_mut() {
+                        time[1] = text;
+                    }
+                }
+                SyntaxKind::TIMESTAMP_REPEATER_MARK | SyntaxKind::TIMESTAMP_DELAY_MARK => {
+                    part.suffixes.push(text.to_string())
+                }
+                SyntaxKind::TIMESTAMP_VALUE | SyntaxKind::TIMESTAMP_UNIT => {
+                    if let Some(suffix) = part.suffixes.last_mut() {
+                        suffix.push_str(text);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let (open, close) = if format.active.unwrap_or(self.is_active()) {
+            ('<', '>')
+        } else {
+            ('[', ']')
+        };
+
+        let mut s = String::new();
+        for (idx, part) in parts.iter().enumerate() {
+            if idx > 0 {
+                s.push_str("--");
+            }
+            s.push(open);
+            s.push_str(&part.date.join("-"));
+            if format.weekday {
+                let [year, month, day] = part.date;
+                let weekday = year
+                    .parse()
+                    .ok()
+                    .zip(month.parse().ok())
+                    .zip(day.parse().ok())
+                    .and_then(|((y, m), d)| weekday_name(y, m, d))
+                    .or(part.dayname);
+                if let Some(weekday) = weekday {
+                    s.push(' ');
+                    s.push_str(weekday);
+                }
+            }
+            if format.time && !part.times.is_empty() {
+                s.push(' ');
+                let times = part
+                    .times
+                    .iter()
+                    .map(|[hour, minute]| format!("{hour:0>2}:{minute}"))
+                    .collect::<Vec<_>>();
+                s.push_str(&times.join("-"));
+            }
+            for suffix in &part.suffixes {
+                s.push(' ');
+                s.push_str(suffix);
+            }
+            s.push(close);
+        }
+
+        s
+    }
+
+    /// Minutes from 1970-01-01 00:00 to timestamp start
+    pub(crate) fn start_minutes(&self) -> Option<i64> {
+        to_minutes(
+            self.year_start()?.parse().ok()?,
+            self.month_start()?.parse().ok()?,
+            self.day_start()?.parse().ok()?,
+            self.hour_start().map_or(Some(0), |h| h.parse().ok())?,
+            self.minute_start().map_or(Some(0), |m| m.parse().ok())?,
+        )
+    }
+
+    /// Minutes from 1970-01-01 00:00 to timestamp end
+    pub(crate) fn end_minutes(&self) -> Option<i64> {
+        to_minutes(
+            self.year_end()?.parse().ok()?,
+            self.month_end()?.parse().ok()?,
+            self.day_end()?.parse().ok()?,
+            self.hour_end().map_or(Some(0), |h| h.parse().ok())?,
+            self.minute_end().map_or(Some(0), |m| m.parse().ok())?,
+        )
+    }
+
     /// Converts timestamp start to chrono NaiveDateTime
     ///
     /// ```rust
@@ -299,3 +730,278 @@ impl Timestamp {
         Some(self.end_to_chrono()? - self.start_to_chrono()?)
     }
 }
+
+impl Timestamp {
+    /// Creates an active date timestamp, like `<2024-01-01 Mon>`
+    ///
+    /// Returns `None` if the date is invalid.
+    ///
+    /// ```rust
+    /// use orgize::ast::Timestamp;
+    ///
+    /// assert_eq!(Timestamp::active_date(2024, 2, 29).unwrap().raw(), "<2024-02-29 Thu>");
+    /// assert_eq!(Timestamp::inactive_date(2024, 1, 1).unwrap().raw(), "[2024-01-01 Mon]");
+    /// assert!(Timestamp::active_date(2023, 2, 29).is_none());
+    /// ```
+    pub fn active_date(year: i32, month: u32, day: u32) -> Option<Timestamp> {
+        build_timestamp(true, year, month, day, &[])
+    }
+
+    /// Creates an inactive date timestamp, like `[2024-01-01 Mon]`, see
+    /// [`Timestamp::active_date`]
+    pub fn inactive_date(year: i32, month: u32, day: u32) -> Option<Timestamp> {
+        build_timestamp(false, year, month, day, &[])
+    }
+
+    /// Creates an active timestamp with time of day, like
+    /// `<2024-01-01 Mon 09:30>`
+    ///
+    /// Returns `None` if the date or time is invalid.
+    ///
+    /// ```rust
+    /// use orgize::ast::Timestamp;
+    ///
+    /// let ts = Timestamp::active_datetime(2024, 1, 1, 9, 30).unwrap();
+    /// assert_eq!(ts.raw(), "<2024-01-01 Mon 09:30>");
+    /// assert_eq!(ts.hour_start().unwrap(), "09");
+    ///
+    /// let ts = Timestamp::inactive_datetime(2024, 1, 1, 23, 5).unwrap();
+    /// assert_eq!(ts.raw(), "[2024-01-01 Mon 23:05]");
+    /// assert!(Timestamp::active_datetime(2024, 1, 1, 24, 0).is_none());
+    /// ```
+    pub fn active_datetime(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+    ) -> Option<Timestamp> {
+        build_timestamp(true, year, month, day, &[(hour, minute)])
+    }
+
+    /// Creates an inactive timestamp with time of day, like
+    /// `[2024-01-01 Mon 09:30]`, see [`Timestamp::active_datetime`]
+    pub fn inactive_datetime(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+    ) -> Option<Timestamp> {
+        build_timestamp(false, year, month, day, &[(hour, minute)])
+    }
+
+    /// Creates an active timestamp with a time range in one day, like
+    /// `<2024-01-01 Mon 09:00-10:30>`
+    ///
+    /// Times are given as `(hour, minute)`. Returns `None` if the date or
+    /// either time is invalid, or if the end is before the start.
+    ///
+    /// ```rust
+    /// use orgize::ast::Timestamp;
+    /// use std::time::Duration;
+    ///
+    /// let ts = Timestamp::active_time_range(2024, 1, 1, (9, 0), (10, 30)).unwrap();
+    /// assert_eq!(ts.raw(), "<2024-01-01 Mon 09:00-10:30>");
+    /// assert_eq!(ts.duration(), Some(Duration::from_secs(90 * 60)));
+    ///
+    /// assert!(Timestamp::inactive_time_range(2024, 1, 1, (10, 0), (9, 0)).is_none());
+    /// ```
+    pub fn active_time_range(
+        year: i32,
+        month: u32,
+        day: u32,
+        start: (u32, u32),
+        end: (u32, u32),
+    ) -> Option<Timestamp> {
+        if end < start {
+            return None;
+        }
+        build_timestamp(true, year, month, day, &[start, end])
+    }
+
+    /// Creates an inactive timestamp with a time range in one day, like
+    /// `[2024-01-01 Mon 09:00-10:30]`, see [`Timestamp::active_time_range`]
+    pub fn inactive_time_range(
+        year: i32,
+        month: u32,
+        day: u32,
+        start: (u32, u32),
+        end: (u32, u32),
+    ) -> Option<Timestamp> {
+        if end < start {
+            return None;
+        }
+        build_timestamp(false, year, month, day, &[start, end])
+    }
+
+    /// Creates a range from two timestamps, like
+    /// `<2024-01-01 Mon>--<2024-01-03 Wed>`
+    ///
+    /// Returns `None` if either timestamp is a range or a diary timestamp,
+    /// if only one of them is active, or if the end is before the start.
+    ///
+    /// ```rust
+    /// use orgize::ast::Timestamp;
+    ///
+    /// let start = Timestamp::inactive_datetime(2024, 1, 1, 23, 0).unwrap();
+    /// let end = Timestamp::inactive_datetime(2024, 1, 2, 1, 0).unwrap();
+    /// let ts = Timestamp::range(&start, &end).unwrap();
+    /// assert_eq!(ts.raw(), "[2024-01-01 Mon 23:00]--[2024-01-02 Tue 01:00]");
+    /// assert!(ts.is_range());
+    ///
+    /// assert!(Timestamp::range(&end, &start).is_none());
+    /// assert!(Timestamp::range(&start, &Timestamp::active_date(2024, 1, 2).unwrap()).is_none());
+    /// ```
+    pub fn range(start: &Timestamp, end: &Timestamp) -> Option<Timestamp> {
+        if start.is_range()
+            || end.is_range()
+            || start.is_diary()
+            || end.is_diary()
+            || start.is_active() != end.is_active()
+            || end.start_minutes()? < start.start_minutes()?
+        {
+            return None;
+        }
+        ParseConfig::default().parse_timestamp(format!("{}--{}", start.raw(), end.raw()))
+    }
+
+    /// Creates a date timestamp from chrono NaiveDate
+    ///
+    /// Returns `None` if the year is outside `0..=9999`, which can't be
+    /// written in a timestamp.
+    ///
+    /// ```rust
+    /// use orgize::ast::Timestamp;
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// assert_eq!(Timestamp::from_naive_date(date, true).unwrap().raw(), "<2024-01-01 Mon>");
+    ///
+    /// let date = NaiveDate::from_ymd_opt(10000, 1, 1).unwrap();
+    /// assert!(Timestamp::from_naive_date(date, true).is_none());
+    /// let date = NaiveDate::from_ymd_opt(-1, 1, 1).unwrap();
+    /// assert!(Timestamp::from_naive_date(date, true).is_none());
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn from_naive_date(date: chrono::NaiveDate, active: bool) -> Option<Timestamp> {
+        use chrono::Datelike;
+
+        build_timestamp(active, date.year(), date.month(), date.day(), &[])
+    }
+
+    /// Creates a timestamp with time of day from chrono NaiveDateTime
+    ///
+    /// Seconds are dropped. Returns `None` if the year is outside
+    /// `0..=9999`, which can't be written in a timestamp.
+    ///
+    /// ```rust
+    /// use orgize::ast::Timestamp;
+    /// use chrono::NaiveDateTime;
+    ///
+    /// let datetime = "2024-01-01T09:30:15".parse::<NaiveDateTime>().unwrap();
+    /// assert_eq!(Timestamp::from_naive_datetime(datetime, false).unwrap().raw(), "[2024-01-01 Mon 09:30]");
+    ///
+    /// let datetime = "+10000-01-01T09:30:15".parse::<NaiveDateTime>().unwrap();
+    /// assert!(Timestamp::from_naive_datetime(datetime, false).is_none());
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn from_naive_datetime(datetime: chrono::NaiveDateTime, active: bool) -> Option<Timestamp> {
+        use chrono::{Datelike, Timelike};
+
+        build_timestamp(
+            active,
+            datetime.year(),
+            datetime.month(),
+            datetime.day(),
+            &[(datetime.hour(), datetime.minute())],
+        )
+    }
+}
+
+/// Formats and parses a timestamp with computed day name
+///
+/// Returns `None` if the date or time is invalid, or the year doesn't have
+/// exactly four digits.
+fn build_timestamp(
+    active: bool,
+    year: i32,
+    month: u32,
+    day: u32,
+    times: &[(u32, u32)],
+) -> Option<Timestamp> {
+    if !(0..=9999).contains(&year) {
+        return None;
+    }
+    let weekday = weekday_name(year as i64, month, day)?;
+    let mut s = format!("{year:04}-{month:02}-{day:02} {weekday}");
+    for (idx, (hour, minute)) in times.iter().enumerate() {
+        to_minutes(year as i64, month, day, *hour, *minute)?;
+        s.push(if idx == 0 { ' ' } else { '-' });
+        s.push_str(&format!("{hour:02}:{minute:02}"));
+    }
+    let s = if active {
+        format!("<{s}>")
+    } else {
+        format!("[{s}]")
+    };
+    ParseConfig::default().parse_timestamp(s)
+}
+
+/// Days from 1970-01-01 to given date in proleptic Gregorian calendar
+///
+/// Returns `None` if the date is invalid.
+///
+/// <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> Option<i64> {
+    let is_leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+    if day == 0 || day > days_in_month {
+        return None;
+    }
+
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Some(era * 146097 + doe - 719468)
+}
+
+/// Date of given days from 1970-01-01, the inverse of [`days_from_civil`]
+///
+/// <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Abbreviated English day name of given date
+fn weekday_name(year: i64, month: u32, day: u32) -> Option<&'static str> {
+    const NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    // 1970-01-01 is a Thursday
+    let days = days_from_civil(year, month, day)?;
+    Some(NAMES[(days + 3).rem_euclid(7) as usize])
+}
+
+fn to_minutes(year: i64, month: u32, day: u32, hour: u32, minute: u32) -> Option<i64> {
+    if hour > 23 || minute > 59 {
+        return None;
+    }
+    Some(days_from_civil(year, month, day)? * 24 * 60 + hour as i64 * 60 + minute as i64)
+}
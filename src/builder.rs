@@ -0,0 +1,230 @@
+//! Build org-mode documents programmatically.
+
+use std::fmt::Write as _;
+
+use crate::{escape, Org, ParseConfig};
+
+/// A writer for assembling org-mode documents
+///
+/// ```rust
+/// use orgize::OrgBuilder;
+///
+/// let org = OrgBuilder::new()
+///     .keyword("TITLE", "Tasks")
+///     .headline(1, "Work")
+///     .tag("work")
+///     .headline(2, "Write report")
+///     .todo("TODO")
+///     .priority('A')
+///     .property("ID", "42")
+///     .body("Due next week.")
+///     .build();
+///
+/// assert_eq!(org, r#"#+TITLE: Tasks
+/// * Work :work:
+/// ** TODO [#A] Write report
+/// :PROPERTIES:
+/// :ID: 42
+/// :END:
+/// Due next week.
+/// "#);
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct OrgBuilder {
+    output: String,
+}
+
+impl OrgBuilder {
+    pub fn new() -> Self {
+        OrgBuilder::default()
+    }
+
+    /// Appends a `#+KEY: VALUE` line
+    ///
+    /// Line breaks in value are replaced with spaces.
+    pub fn keyword(mut self, key: &str, value: &str) -> Self {
+        let _ = writeln!(&mut self.output, "#+{}: {}", key, single_line(value));
+        self
+    }
+
+    /// Appends raw org-mode text
+    ///
+    /// A trailing newline is added if text doesn't end with one.
+    pub fn text(mut self, text: &str) -> Self {
+        push_text(&mut self.output, text);
+        self
+    }
+
+    /// Starts a new headline at given level
+    ///
+    /// Level is at least 1. Title is sanitized with [`escape::headline_title`].
+    pub fn headline(self, level: usize, title: &str) -> HeadlineBuilder {
+        HeadlineBuilder {
+            org: self,
+            level: level.max(1),
+            title: escape::headline_title(title),
+            todo: None,
+            priority: None,
+            tags: vec![],
+            properties: vec![],
+            body: String::new(),
+        }
+    }
+
+    /// Returns the org-mode text
+    pub fn build(self) -> String {
+        self.output
+    }
+
+    /// Parses the org-mode text with default config
+    ///
+    /// ```rust
+    /// use orgize::{OrgBuilder, ast::Headline};
+    ///
+    /// let org = OrgBuilder::new()
+    ///     .headline(1, "multi\nline")
+    ///     .todo("DONE")
+    ///     .tag("a b")
+    ///     .finish()
+    ///     .parse();
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.title_raw().trim(), "multi line");
+    /// assert!(hdl.is_done());
+    /// assert_eq!(hdl.tags().map(|t| t.to_string()).collect::<Vec<_>>(), vec!["a_b"]);
+    /// ```
+    pub fn parse(self) -> Org {
+        Org::parse(self.output)
+    }
+
+    /// Parses the org-mode text with given config
+    pub fn parse_with_config(self, config: ParseConfig) -> Org {
+        config.parse(self.output)
+    }
+}
+
+/// A headline being built by [`OrgBuilder`]
+///
+/// Calling [`HeadlineBuilder::headline`] or [`HeadlineBuilder::finish`]
+/// writes this headline into the document.
+#[derive(Debug, Clone)]
+pub struct HeadlineBuilder {
+    org: OrgBuilder,
+    level: usize,
+    title: String,
+    todo: Option<String>,
+    priority: Option<char>,
+    tags: Vec<String>,
+    properties: Vec<(String, String)>,
+    body: String,
+}
+
+impl HeadlineBuilder {
+    /// Sets todo keyword
+    pub fn todo(mut self, keyword: &str) -> Self {
+        self.todo = Some(keyword.to_string());
+        self
+    }
+
+    /// Sets priority
+    pub fn priority(mut self, priority: char) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Appends a tag
+    ///
+    /// Tag is sanitized with [`escape::tag`].
+    pub fn tag(mut self, tag: &str) -> Self {
+        let tag = escape::tag(tag);
+        if !tag.is_empty() {
+            self.tags.push(tag);
+        }
+        self
+    }
+
+    /// Appends a property to the property drawer
+    ///
+    /// Whitespace and colons in key are replaced with `_`, and line breaks
+    /// in value are replaced with spaces.
+    pub fn property(mut self, key: &str, value: &str) -> Self {
+        let key = key.replace(|c: char| c.is_whitespace() || c == ':', "_");
+        self.properties.push((key, single_line(value)));
+        self
+    }
+
+    /// Appends raw org-mode text to the section of this headline
+    pub fn body(mut self, text: &str) -> Self {
+        push_text(&mut self.body, text);
+        self
+    }
+
+    /// Writes this headline and starts a new one
+    pub fn headline(self, level: usize, title: &str) -> HeadlineBuilder {
+        self.finish().headline(level, title)
+    }
+
+    /// Writes this headline and returns the document builder
+    pub fn finish(self) -> OrgBuilder {
+        let mut org = self.org;
+        let output = &mut org.output;
+
+        output.push_str(&"*".repeat(self.level));
+        if let Some(todo) = &self.todo {
+            output.push(' ');
+            output.push_str(todo);
+        }
+        if let Some(priority) = self.priority {
+            let _ = write!(output, " [#{priority}]");
+        }
+        if !self.title.is_empty() {
+            output.push(' ');
+            output.push_str(&self.title);
+        }
+        if !self.tags.is_empty() {
+            let _ = write!(output, " :{}:", self.tags.join(":"));
+        }
+        if self.todo.is_none() && self.priority.is_none() && self.title.is_empty() {
+            // a headline must have a space after stars
+            output.push(' ');
+        }
+        output.push('\n');
+
+        if !self.properties.is_empty() {
+            output.push_str(":PROPERTIES:\n");
+            for (key, value) in &self.properties {
+                let _ = writeln!(output, ":{key}: {value}");
+            }
+            output.push_str(":END:\n");
+        }
+
+        output.push_str(&self.body);
+
+        org
+    }
+
+    /// Writes this headline and returns the org-mode text
+    pub fn build(self) -> String {
+        self.finish().build()
+    }
+
+    /// Writes this headline and parses the org-mode text with default config
+    pub fn parse(self) -> Org {
+        self.finish().parse()
+    }
+}
+
+fn single_line(s: &str) -> String {
+    s.split(['\n', '\r'])
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim()
+        .to_string()
+}
+
+fn push_text(output: &mut String, text: &str) {
+    output.push_str(text);
+    if !text.is_empty() && !text.ends_with('\n') {
+        output.push('\n');
+    }
+}
@@ -0,0 +1,160 @@
+use rowan::{NodeOrToken, WalkEvent};
+
+use crate::{syntax::SyntaxKind, Org, SyntaxNode};
+
+/// Controls which differences [`Org::structurally_eq_with`] ignores
+#[derive(Clone, Debug)]
+pub struct CompareOptions {
+    /// Ignore spaces and tabs at the end of lines
+    ///
+    /// Defaults to `true`.
+    pub trailing_whitespace: bool,
+
+    /// Ignore blank lines, i.e. the number of blank lines between elements
+    /// and whether an element is followed by blank lines at all
+    ///
+    /// Defaults to `true`.
+    pub blank_lines: bool,
+}
+
+impl Default for CompareOptions {
+    fn default() -> Self {
+        CompareOptions {
+            trailing_whitespace: true,
+            blank_lines: true,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Item {
+    Enter(SyntaxKind),
+    Leave,
+    Token(SyntaxKind, String),
+}
+
+impl CompareOptions {
+    fn items(&self, root: &SyntaxNode) -> Vec<Item> {
+        let mut items = vec![];
+
+        let mut skipped = None;
+        let mut preorder = root.preorder_with_tokens();
+        while let Some(event) = preorder.next() {
+            match event {
+                WalkEvent::Enter(elem) => {
+                    if self.blank_lines && elem.kind() == SyntaxKind::BLANK_LINE {
+                        continue;
+                    }
+                    match elem {
+                        NodeOrToken::Node(node) => {
+                            // e.g. paragraphs or sections containing only blank lines
+                            if self.blank_lines && node.text().to_string().trim().is_empty() {
+                                preorder.skip_subtree();
+                                skipped = Some(node);
+                                continue;
+                            }
+                            items.push(Item::Enter(node.kind()));
+                        }
+                        NodeOrToken::Token(token) => {
+                            items.push(Item::Token(token.kind(), token.text().to_string()));
+                        }
+                    }
+                }
+                WalkEvent::Leave(NodeOrToken::Node(node)) => {
+                    // skipped node still emits a leave event
+                    if skipped.as_ref() != Some(&node) {
+                        items.push(Item::Leave)
+                    }
+                }
+                WalkEvent::Leave(_) => {}
+            }
+        }
+
+        if self.trailing_whitespace {
+            trim_trailing_whitespace(&mut items);
+        }
+
+        items
+    }
+}
+
+/// Removes spaces and tabs before line endings, including those that end
+/// a token followed by a line ending in the next token
+fn trim_trailing_whitespace(items: &mut Vec<Item>) {
+    let mut next_starts_line_end = true;
+
+    for item in items.iter_mut().rev() {
+        let Item::Token(_, text) = item else {
+            continue;
+        };
+
+        let mut trimmed = String::with_capacity(text.len());
+        for line in text.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\r', '\n']);
+            trimmed.push_str(content.trim_end_matches([' ', '\t']));
+            trimmed.push_str(&line[content.len()..]);
+        }
+        if next_starts_line_end {
+            trimmed.truncate(trimmed.trim_end_matches([' ', '\t']).len());
+        }
+
+        if !trimmed.is_empty() {
+            next_starts_line_end = trimmed.starts_with(['\r', '\n']);
+        }
+        *text = trimmed;
+    }
+
+    items.retain(|item| !matches!(item, Item::Token(_, text) if text.is_empty()));
+}
+
+impl Org {
+    /// Returns `true` if both documents have the same structure and content,
+    /// ignoring trailing whitespace and blank lines
+    ///
+    /// Equivalent to `structurally_eq_with(other, &CompareOptions::default())`.
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// let a = Org::parse("* a\n\n\ntext  \n* b :tag:\n");
+    /// let b = Org::parse("* a\ntext\n\n* b :tag:\n");
+    /// assert!(a.structurally_eq(&b));
+    ///
+    /// let c = Org::parse("* a\ntext\n* b :other:\n");
+    /// assert!(!a.structurally_eq(&c));
+    /// ```
+    pub fn structurally_eq(&self, other: &Org) -> bool {
+        self.structurally_eq_with(other, &CompareOptions::default())
+    }
+
+    /// Returns `true` if both documents have the same structure and content,
+    /// ignoring differences specified in `options`
+    ///
+    /// Documents are compared node by node, so the same text parsed with
+    /// different configs might not be equal. Besides what `options` allows,
+    /// all text is compared exactly, including indentation and the newline
+    /// at the end of document.
+    ///
+    /// ```rust
+    /// use orgize::{CompareOptions, Org};
+    ///
+    /// let a = Org::parse("paragraph 1\n\nparagraph 2 \n");
+    /// let b = Org::parse("paragraph 1\n\n\nparagraph 2\n");
+    /// assert!(a.structurally_eq(&b));
+    ///
+    /// let options = CompareOptions { blank_lines: false, ..Default::default() };
+    /// assert!(!a.structurally_eq_with(&b, &options));
+    ///
+    /// let options = CompareOptions { trailing_whitespace: false, ..Default::default() };
+    /// assert!(!a.structurally_eq_with(&b, &options));
+    ///
+    /// // blank lines separate paragraphs, so they can't be ignored entirely
+    /// let c = Org::parse("paragraph 1\nparagraph 2\n");
+    /// assert!(!a.structurally_eq(&c));
+    /// ```
+    pub fn structurally_eq_with(&self, other: &Org, options: &CompareOptions) -> bool {
+        let a = SyntaxNode::new_root(self.green.clone());
+        let b = SyntaxNode::new_root(other.green.clone());
+        options.items(&a) == options.items(&b)
+    }
+}
@@ -1,4 +1,16 @@
-use crate::syntax::document::document_node;
+use nom::IResult;
+use rowan::ast::AstNode;
+
+use crate::ast::{Headline, Paragraph, Timestamp};
+use crate::syntax::{
+    combinator::GreenElement,
+    document::document_node,
+    headline::headline_node,
+    input::Input,
+    paragraph::paragraph_node,
+    timestamp::{timestamp_active_node, timestamp_diary_node, timestamp_inactive_node},
+    OrgLanguage, SyntaxNode,
+};
 use crate::Org;
 
 #[derive(Clone, Debug)]
@@ -45,19 +57,220 @@ pub struct ParseConfig {
     ///
     /// Equivalent to [`org-element-affiliated-keywords`](https://git.sr.ht/~bzg/org-mode/tree/6f960f3c6a4dfe137fbd33fef9f7dadfd229600c/item/lisp/org-element.el#L331)
     pub affiliated_keywords: Vec<String>,
+
+    /// Only recognize headlines with an odd number of stars
+    ///
+    /// Equivalent to `#+STARTUP: odd`. When enabled, a line starting with
+    /// `**` or `****` is not a headline.
+    ///
+    /// ```rust
+    /// use orgize::{ParseConfig, ast::Headline, rowan::ast::AstNode};
+    ///
+    /// let input = "* a\n** b\n*** c";
+    /// let org = ParseConfig { odd_levels_only: true, ..Default::default() }.parse(input);
+    /// let count = org.document().syntax().descendants().filter_map(Headline::cast).count();
+    /// assert_eq!(count, 2);
+    ///
+    /// // detect the in-buffer setting, then parse again
+    /// let org = orgize::Org::parse("#+STARTUP: overview odd\n* a\n** b");
+    /// let odd = org.startup_options().iter().any(|o| o == "odd");
+    /// let org = ParseConfig { odd_levels_only: odd, ..Default::default() }.parse(org.to_org());
+    /// assert_eq!(org.document().headlines().next().unwrap().headlines().count(), 0);
+    /// ```
+    pub odd_levels_only: bool,
+
+    /// Highest, lowest and default priority
+    ///
+    /// Equivalent to `org-priority-highest`, `org-priority-lowest` and
+    /// `org-priority-default`. Defaults to `('A', 'C', 'B')`. Numeric
+    /// priorities can be configured with `('1', '9', '5')`.
+    ///
+    /// Priority cookies outside this range are still parsed, but
+    /// [`ParseConfig::priority_rank`] returns `None` for them.
+    pub priorities: (char, char, char),
+
+    /// Allow single letters as ordered list bullets
+    ///
+    /// Equivalent to `org-list-allow-alphabetical`. When enabled, lines
+    /// starting with `a.`, `b)` or `A.` begin ordered list items. Defaults
+    /// to `false`, so those lines are parsed as paragraphs.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ParseConfig, ast::List};
+    ///
+    /// let config = ParseConfig { alphabetical_lists: true, ..Default::default() };
+    /// let org = config.parse("a. first\nb) second");
+    /// let list = org.first_node::<List>().unwrap();
+    /// assert!(list.is_ordered());
+    /// assert_eq!(list.items().count(), 2);
+    ///
+    /// assert!(Org::parse("a. first").first_node::<List>().is_none());
+    /// ```
+    pub alphabetical_lists: bool,
 }
 
 impl ParseConfig {
+    /// Returns the rank of given priority, where `0` is the highest
+    ///
+    /// Returns `None` if the priority is out of the configured range.
+    ///
+    /// ```rust
+    /// use orgize::ParseConfig;
+    ///
+    /// let config = ParseConfig::default();
+    /// assert_eq!(config.priority_rank('A'), Some(0));
+    /// assert_eq!(config.priority_rank('C'), Some(2));
+    /// assert_eq!(config.priority_rank('D'), None);
+    ///
+    /// let config = ParseConfig { priorities: ('1', '9', '5'), ..Default::default() };
+    /// assert_eq!(config.priority_rank('3'), Some(2));
+    /// assert_eq!(config.priority_rank('A'), None);
+    /// ```
+    pub fn priority_rank(&self, priority: char) -> Option<u32> {
+        let (highest, lowest, _) = self.priorities;
+        (highest..=lowest)
+            .contains(&priority)
+            .then(|| priority as u32 - highest as u32)
+    }
+
     /// Parses input with current config
+    ///
+    /// Todo keywords declared in-buffer with `#+TODO`, `#+SEQ_TODO` or
+    /// `#+TYP_TODO` are added to [`ParseConfig::todo_keywords`], see
+    /// [`Document::todo_keywords`](crate::ast::Document::todo_keywords).
+    /// They're read again whenever the document is edited, e.g. with
+    /// [`Org::replace_range`], so they never outlive their keyword line.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{Headline, TodoType}};
+    ///
+    /// let org = Org::parse("#+TODO: A B | C\n* C x\n* A x\n* TODO x");
+    /// let types = org.headlines().map(|h| h.todo_type()).collect::<Vec<_>>();
+    /// assert_eq!(types, vec![Some(TodoType::Done), Some(TodoType::Todo), Some(TodoType::Todo)]);
+    /// assert_eq!(org.config().todo_keywords.0, ["TODO", "A", "B"]);
+    /// assert_eq!(org.config().todo_keywords.1, ["DONE", "C"]);
+    ///
+    /// let org = Org::parse("* C x");
+    /// assert!(org.first_node::<Headline>().unwrap().todo_type().is_none());
+    /// ```
     pub fn parse(self, input: impl AsRef<str>) -> Org {
-        let input = (input.as_ref(), &self).into();
-        let node = document_node(input).unwrap().1;
+        let input = input.as_ref();
+        let node = document_node((input, &self).into()).unwrap().1;
 
-        Org {
-            config: self,
+        let mut org = Org {
+            config: self.clone(),
+            base_config: self,
             green: node.into_node().unwrap(),
+            names: Default::default(),
+            line_starts: Default::default(),
+        };
+        org.sync_buffer_keywords();
+        org
+    }
+
+    /// Returns a copy of this config, with given in-buffer todo keywords
+    /// added to [`ParseConfig::todo_keywords`]
+    pub(crate) fn with_buffer_todo_keywords(
+        &self,
+        (todo, done): (Vec<String>, Vec<String>),
+    ) -> ParseConfig {
+        let mut config = self.clone();
+        let (active, finished) = &mut config.todo_keywords;
+        for (states, list) in [(todo, active), (done, finished)] {
+            for state in states {
+                if !list.contains(&state) {
+                    list.push(state);
+                }
+            }
+        }
+        config
+    }
+
+    /// Parses input as a single headline, including its section and
+    /// sub-headlines
+    ///
+    /// Returns `None` if input isn't a headline or contains anything after it,
+    /// e.g. a sibling headline.
+    ///
+    /// ```rust
+    /// use orgize::ParseConfig;
+    ///
+    /// let config = ParseConfig::default();
+    /// let hdl = config.parse_headline("* TODO foo\n** bar").unwrap();
+    /// assert_eq!(hdl.title_raw(), "foo");
+    /// assert_eq!(hdl.headlines().count(), 1);
+    ///
+    /// assert!(config.parse_headline("foo").is_none());
+    /// assert!(config.parse_headline("* foo\n* bar").is_none());
+    /// ```
+    pub fn parse_headline(&self, input: impl AsRef<str>) -> Option<Headline> {
+        self.parse_exact(input.as_ref(), headline_node)
+    }
+
+    /// Parses input as a single timestamp
+    ///
+    /// Returns `None` if input isn't a valid timestamp or contains anything
+    /// after it, including whitespace.
+    ///
+    /// ```rust
+    /// use orgize::ParseConfig;
+    ///
+    /// let config = ParseConfig::default();
+    /// let ts = config.parse_timestamp("<2024-01-01 Mon 10:00>").unwrap();
+    /// assert!(ts.is_active());
+    /// assert!(config.parse_timestamp("[2024-01-01]").unwrap().is_inactive());
+    /// assert!(config.parse_timestamp("<%%(diary-float t 4 2)>").unwrap().is_diary());
+    ///
+    /// assert!(config.parse_timestamp("<2024-01-01").is_none());
+    /// assert!(config.parse_timestamp("<2024-01-01> ").is_none());
+    /// ```
+    pub fn parse_timestamp(&self, input: impl AsRef<str>) -> Option<Timestamp> {
+        let input = input.as_ref();
+        match input.as_bytes().first() {
+            Some(b'<') => self
+                .parse_exact(input, timestamp_diary_node)
+                .or_else(|| self.parse_exact(input, timestamp_active_node)),
+            Some(b'[') => self.parse_exact(input, timestamp_inactive_node),
+            _ => None,
         }
     }
+
+    /// Parses input as a single paragraph
+    ///
+    /// Returns `None` if input is empty or contains anything after the
+    /// paragraph and its trailing blank lines.
+    ///
+    /// ```rust
+    /// use orgize::ParseConfig;
+    ///
+    /// let config = ParseConfig::default();
+    /// let paragraph = config.parse_paragraph("*bold*\ntext\n\n").unwrap();
+    /// assert_eq!(paragraph.text(), "bold\ntext\n");
+    ///
+    /// assert!(config.parse_paragraph("").is_none());
+    /// assert!(config.parse_paragraph("a\n\nb").is_none());
+    /// ```
+    pub fn parse_paragraph(&self, input: impl AsRef<str>) -> Option<Paragraph> {
+        self.parse_exact(input.as_ref(), paragraph_node)
+    }
+
+    fn parse_exact<N: AstNode<Language = OrgLanguage>>(
+        &self,
+        input: &str,
+        parser: impl Fn(Input) -> IResult<Input, GreenElement, ()>,
+    ) -> Option<N> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let (rest, element) = parser((input, self).into()).ok()?;
+
+        if !rest.is_empty() {
+            return None;
+        }
+
+        N::cast(SyntaxNode::new_root(element.into_node()?))
+    }
 }
 
 impl Default for ParseConfig {
@@ -67,6 +280,9 @@ impl Default for ParseConfig {
             dual_keywords: vec!["CAPTION".into(), "RESULTS".into()],
             parsed_keywords: vec!["CAPTION".into()],
             use_sub_superscript: UseSubSuperscript::True,
+            odd_levels_only: false,
+            priorities: ('A', 'C', 'B'),
+            alphabetical_lists: false,
             affiliated_keywords: vec![
                 "CAPTION".into(),
                 "DATA".into(),
@@ -85,3 +301,23 @@ impl Default for ParseConfig {
         }
     }
 }
+
+impl Org {
+    /// Recomputes todo keywords declared in-buffer, and parses the document
+    /// again if they changed
+    ///
+    /// Keywords are always added to the base config given by user, so
+    /// removing a `#+TODO` line also removes its keywords.
+    pub(crate) fn sync_buffer_keywords(&mut self) {
+        let config = self
+            .base_config
+            .with_buffer_todo_keywords(self.todo_keywords());
+
+        if config.todo_keywords != self.config.todo_keywords {
+            let input = self.green.to_string();
+            let node = document_node((input.as_str(), &config).into()).unwrap().1;
+            self.green = node.into_node().unwrap();
+            self.config = config;
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Sanitize strings for safe insertion into org-mode text.
+//!
+//! Org-mode has no general escape syntax. Where a string can't be inserted
+//! as is, these helpers either replace the offending characters or insert
+//! a zero width space (`U+200B`), the escape character recommended by the
+//! org-mode manual.
+
+use crate::ParseConfig;
+
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Sanitizes a string for use as a headline title, using default todo
+/// keywords
+///
+/// Equivalent to `headline_title_with(s, &ParseConfig::default())`.
+///
+/// - line breaks are replaced with spaces, so the title can't start a new
+///   line (and a new headline, if the next line begins with `*`)
+/// - leading and trailing whitespace is trimmed
+/// - a leading `TODO`, `DONE` or `COMMENT` word, or a leading priority
+///   cookie like `[#A]` followed by whitespace, is escaped so it stays part
+///   of the title
+/// - a trailing colon is escaped so the end of the title isn't parsed as
+///   tags
+///
+/// ```rust
+/// use orgize::{escape, Org, ast::Headline};
+///
+/// assert_eq!(escape::headline_title("first\n* second"), "first * second");
+/// assert_eq!(escape::headline_title("  plain  "), "plain");
+///
+/// let title = escape::headline_title("TODO list :a:b:");
+/// let org = Org::parse(format!("* {title}"));
+/// let hdl = org.first_node::<Headline>().unwrap();
+/// assert!(hdl.todo_keyword().is_none());
+/// assert_eq!(hdl.tags().count(), 0);
+///
+/// assert_eq!(escape::headline_title("[#A] first"), "\u{200B}[#A] first");
+/// assert_eq!(escape::headline_title("[#A]"), "\u{200B}[#A]");
+/// assert_eq!(escape::headline_title("[#A]foo"), "[#A]foo");
+/// ```
+pub fn headline_title(s: &str) -> String {
+    headline_title_with(s, &ParseConfig::default())
+}
+
+/// Sanitizes a string for use as a headline title
+///
+/// Same as [`headline_title`], but a leading word is escaped if it's one of
+/// the todo keywords in [`ParseConfig::todo_keywords`].
+///
+/// ```rust
+/// use orgize::{escape, ParseConfig, ast::Headline};
+///
+/// let config = ParseConfig {
+///     todo_keywords: (vec!["WAIT".into()], vec!["CANCELED".into()]),
+///     ..Default::default()
+/// };
+/// let title = escape::headline_title_with("WAIT for it", &config);
+/// let hdl = config.parse_headline(format!("* {title}")).unwrap();
+/// assert!(hdl.todo_keyword().is_none());
+/// assert_eq!(hdl.title_raw(), "\u{200B}WAIT for it");
+///
+/// assert_eq!(escape::headline_title_with("TODO list", &config), "TODO list");
+/// ```
+pub fn headline_title_with(s: &str, config: &ParseConfig) -> String {
+    let mut title = s
+        .split(['\n', '\r'])
+        .filter(|l| !l.trim().is_empty())
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let first_word = title.split([' ', '\t']).next().unwrap_or_default();
+    let (todo, done) = &config.todo_keywords;
+    if first_word == "COMMENT"
+        || todo.iter().chain(done).any(|k| k == first_word)
+        || is_priority_cookie(first_word)
+    {
+        title.insert(0, ZERO_WIDTH_SPACE);
+    }
+
+    if title.ends_with(':') {
+        title.push(ZERO_WIDTH_SPACE);
+    }
+
+    title
+}
+
+/// Sanitizes a string for use as a headline tag
+///
+/// Tags may only contain alphanumeric characters, `_`, `@`, `#` and `%`.
+/// Any other character, including whitespace and `:`, is replaced with `_`.
+///
+/// ```rust
+/// use orgize::escape;
+///
+/// assert_eq!(escape::tag("work"), "work");
+/// assert_eq!(escape::tag("to do:later"), "to_do_later");
+/// assert_eq!(escape::tag("@home"), "@home");
+/// ```
+pub fn tag(s: &str) -> String {
+    s.chars()
+        .map(|c| if is_tag_char(c) { c } else { '_' })
+        .collect()
+}
+
+/// Returns true if given character can be used in a headline tag
+pub(crate) fn is_tag_char(c: char) -> bool {
+    // https://github.com/yyr/org-mode/blob/d8494b5668ad4d4e68e83228ae8451eaa01d2220/lisp/org-element.el#L922C25-L922C32
+    c.is_alphanumeric() || c == '_' || c == '@' || c == '#' || c == '%'
+}
+
+/// Returns `true` if `s` starts with a priority cookie like `[#A]`, followed
+/// by whitespace or nothing
+fn is_priority_cookie(s: &str) -> bool {
+    let Some(rest) = s.strip_prefix("[#") else {
+        return false;
+    };
+    let mut chars = rest.chars();
+    chars.next().is_some()
+        && chars.next() == Some(']')
+        && matches!(chars.next(), None | Some(' ' | '\t'))
+}
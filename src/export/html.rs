@@ -1,11 +1,14 @@
-use rowan::NodeOrToken;
+use rowan::{ast::AstNode, Direction, NodeOrToken, TextSize};
 use std::cmp::min;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fmt::Write as _;
 
 use super::event::{Container, Event};
 use super::TraversalContext;
 use super::Traverser;
+use crate::ast::*;
+use crate::resolve::element_name;
 use crate::{SyntaxElement, SyntaxKind, SyntaxNode};
 
 /// A wrapper for escaping sensitive characters in html.
@@ -47,6 +50,11 @@ impl<S: AsRef<str>> fmt::Display for HtmlEscape<S> {
     }
 }
 
+/// Renders org syntax tree as HTML
+///
+/// Of the toggles in `#+OPTIONS`, only `^:` (sub/superscripts) and `\n:`
+/// (preserve line breaks) are supported. Others, like `toc:`, `num:`,
+/// `todo:` or `tags:`, are ignored.
 #[derive(Default)]
 pub struct HtmlExport {
     output: String,
@@ -54,6 +62,182 @@ pub struct HtmlExport {
     in_descriptive_list: Vec<bool>,
 
     table_row: TableRow,
+
+    /// Alignment of each column in current table
+    table_alignments: Vec<Option<ColumnAlign>>,
+
+    drawers: HtmlDrawers,
+
+    link_abbreviations: HashMap<String, String>,
+
+    /// Text of coderef links, keyed by label
+    coderefs: HashMap<String, String>,
+
+    /// Number of the last line in previous numbered block
+    last_line_number: u32,
+
+    /// Labels of captioned figures and tables, like `Figure 1`
+    captions: HashMap<SyntaxNode, String>,
+
+    /// Link texts of named elements, keyed by name
+    ///
+    /// It's the label for captioned figures and tables, or the name itself.
+    references: HashMap<String, String>,
+
+    /// Names of elements rendered with an anchor, i.e. named elements
+    /// without caption
+    anchors: HashMap<SyntaxNode, String>,
+
+    heading_ids: HtmlHeadingIds,
+
+    /// `id` attributes of headlines
+    headline_ids: HashMap<SyntaxNode, String>,
+
+    /// Which subscripts and superscripts are rendered, from `^:` in
+    /// `#+OPTIONS`
+    sub_superscripts: SubSuperscripts,
+
+    /// Whether newlines in paragraphs are rendered as `<br/>`, from `\n:`
+    /// in `#+OPTIONS`
+    preserve_breaks: bool,
+
+    /// End of current paragraph's content, set when entering a paragraph
+    /// if newlines are preserved
+    paragraph_end: Option<TextSize>,
+}
+
+#[derive(Default, PartialEq, Eq)]
+enum SubSuperscripts {
+    /// `^:t`
+    #[default]
+    All,
+    /// `^:{}`, only `a_{b}` and `a^{b}` are rendered
+    Braced,
+    /// `^:nil`
+    None,
+}
+
+/// Controls which drawers are rendered by [`HtmlExport`]
+///
+/// Rendered drawers are wrapped in `<div class="drawer">`. Property drawers
+/// are never rendered.
+#[derive(Default, Debug, Clone)]
+pub enum HtmlDrawers {
+    /// Hides all drawers, including `LOGBOOK`
+    #[default]
+    Hide,
+    /// Renders all drawers
+    All,
+    /// Renders only drawers with given names, compared case-insensitively
+    Only(Vec<String>),
+}
+
+impl HtmlDrawers {
+    fn is_rendered(&self, name: &str) -> bool {
+        match self {
+            HtmlDrawers::Hide => false,
+            HtmlDrawers::All => true,
+            HtmlDrawers::Only(names) => names.iter().any(|n| n.eq_ignore_ascii_case(name)),
+        }
+    }
+}
+
+/// Controls how [`HtmlExport`] generates `id` attributes of headings
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HtmlHeadingIds {
+    /// Headings have no `id` attribute
+    #[default]
+    None,
+    /// GitHub-style slug of the title, like `hello-world`
+    ///
+    /// Markup is stripped, and letters are lowercased. Duplicated slugs
+    /// get a numeric suffix, like `intro-1`.
+    Slug,
+    /// `CUSTOM_ID` property if present, [`HtmlHeadingIds::Slug`] otherwise
+    CustomIdOrSlug,
+}
+
+/// Returns GitHub-style slug of given text
+fn slugify(text: &str) -> String {
+    text.trim()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Returns syntax node of containers that can have affiliated keywords
+fn element_syntax(container: &Container) -> Option<&SyntaxNode> {
+    match container {
+        Container::Paragraph(x) => Some(&x.syntax),
+        Container::OrgTable(x) => Some(&x.syntax),
+        Container::TableEl(x) => Some(&x.syntax),
+        Container::List(x) => Some(&x.syntax),
+        Container::Drawer(x) => Some(&x.syntax),
+        Container::DynBlock(x) => Some(&x.syntax),
+        Container::FixedWidth(x) => Some(&x.syntax),
+        Container::SpecialBlock(x) => Some(&x.syntax),
+        Container::QuoteBlock(x) => Some(&x.syntax),
+        Container::CenterBlock(x) => Some(&x.syntax),
+        Container::VerseBlock(x) => Some(&x.syntax),
+        Container::ExampleBlock(x) => Some(&x.syntax),
+        Container::ExportBlock(x) => Some(&x.syntax),
+        Container::SourceBlock(x) => Some(&x.syntax),
+        _ => None,
+    }
+}
+
+/// Returns the image link if paragraph contains nothing but an image
+fn figure_image(paragraph: &Paragraph) -> Option<Link> {
+    let mut objects = paragraph
+        .objects()
+        .filter(|e| e.as_token().is_none_or(|t| !t.text().trim().is_empty()));
+    let link = objects.next()?.into_node().and_then(Link::cast)?;
+    (objects.next().is_none() && link.is_image()).then_some(link)
+}
+
+/// A line in source or example block
+struct CodeLine<'a> {
+    number: Option<u32>,
+    label: Option<&'a str>,
+    text: &'a str,
+    newline: bool,
+}
+
+/// Splits code into lines, resolving line numbers and coderef labels
+fn code_lines<'a>(
+    switches: &BlockSwitches,
+    value: &'a str,
+    last_line_number: &mut u32,
+) -> Vec<CodeLine<'a>> {
+    let first = switches
+        .number_lines
+        .map(|n| n.first_line(*last_line_number));
+
+    let lines: Vec<CodeLine> = value
+        .split_inclusive('\n')
+        .enumerate()
+        .map(|(idx, line)| {
+            let text = line.trim_end_matches(['\n', '\r']);
+            let label = switches.find_label(text);
+            CodeLine {
+                number: first.map(|first| first + idx as u32),
+                label: label.map(|(_, label)| label),
+                text: match label {
+                    Some((end, _)) if !switches.retain_labels => &text[..end],
+                    _ => text,
+                },
+                newline: text.len() != line.len(),
+            }
+        })
+        .collect();
+
+    if let Some(number) = lines.last().and_then(|line| line.number) {
+        *last_line_number = number;
+    }
+
+    lines
 }
 
 #[derive(Default, PartialEq, Eq)]
@@ -74,6 +258,236 @@ impl HtmlExport {
         self.output
     }
 
+    /// Sets which drawers are rendered, defaults to [`HtmlDrawers::Hide`]
+    ///
+    /// ```rust
+    /// use orgize::{Org, export::{HtmlDrawers, HtmlExport}};
+    ///
+    /// let org = Org::parse(":NOTES:\n*note*\n:END:\n:LOGBOOK:\nCLOCK: [2024-01-01 Mon 10:00]\n:END:\n");
+    ///
+    /// let mut html = HtmlExport::default();
+    /// org.traverse(&mut html);
+    /// assert_eq!(html.finish(), "<main><section></section></main>");
+    ///
+    /// let mut html = HtmlExport::default().with_drawers(HtmlDrawers::Only(vec!["notes".into()]));
+    /// org.traverse(&mut html);
+    /// assert_eq!(
+    ///     html.finish(),
+    ///     "<main><section><div class=\"drawer\"><p><b>note</b>\n</p></div></section></main>"
+    /// );
+    /// ```
+    pub fn with_drawers(mut self, drawers: HtmlDrawers) -> Self {
+        self.drawers = drawers;
+        self
+    }
+
+    /// Sets how `id` attributes of headings are generated, defaults to
+    /// [`HtmlHeadingIds::None`]
+    ///
+    /// ```rust
+    /// use orgize::{Org, export::{HtmlExport, HtmlHeadingIds}};
+    ///
+    /// let org = Org::parse("* Intro\n* *Intro*\n* Über Café\n* Setup\n:PROPERTIES:\n:CUSTOM_ID: install\n:END:");
+    ///
+    /// let mut html = HtmlExport::default().with_heading_ids(HtmlHeadingIds::Slug);
+    /// org.traverse(&mut html);
+    /// let html = html.finish();
+    /// assert!(html.contains(r#"<h1 id="intro">Intro</h1>"#));
+    /// assert!(html.contains(r#"<h1 id="intro-1"><b>Intro</b></h1>"#));
+    /// assert!(html.contains(r#"<h1 id="über-café">Über Café</h1>"#));
+    /// assert!(html.contains(r#"<h1 id="setup">Setup</h1>"#));
+    ///
+    /// let mut html = HtmlExport::default().with_heading_ids(HtmlHeadingIds::CustomIdOrSlug);
+    /// org.traverse(&mut html);
+    /// assert!(html.finish().contains(r#"<h1 id="install">Setup</h1>"#));
+    /// ```
+    pub fn with_heading_ids(mut self, heading_ids: HtmlHeadingIds) -> Self {
+        self.heading_ids = heading_ids;
+        self
+    }
+
+    /// Reads supported toggles in top-level `#+OPTIONS`, the last one wins
+    fn read_options(&mut self, document: &Document) {
+        self.sub_superscripts = SubSuperscripts::All;
+        self.preserve_breaks = false;
+
+        let options = document
+            .keywords()
+            .filter(|kw| kw.key().eq_ignore_ascii_case("OPTIONS"))
+            .map(|kw| kw.value().to_string())
+            .collect::<Vec<_>>();
+
+        for (key, value) in options
+            .iter()
+            .flat_map(|value| value.split_whitespace())
+            .filter_map(|option| option.split_once(':'))
+        {
+            match key {
+                "^" => {
+                    self.sub_superscripts = match value {
+                        "nil" => SubSuperscripts::None,
+                        "{}" => SubSuperscripts::Braced,
+                        _ => SubSuperscripts::All,
+                    }
+                }
+                "\\n" => self.preserve_breaks = value != "nil",
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns `true` if a subscript or superscript should be rendered as is
+    fn is_literal_script(&self, is_braced: bool) -> bool {
+        match self.sub_superscripts {
+            SubSuperscripts::All => false,
+            SubSuperscripts::Braced => !is_braced,
+            SubSuperscripts::None => true,
+        }
+    }
+
+    /// Generates `id` attributes of headlines in document order
+    fn collect_headline_ids(&mut self, node: &SyntaxNode) {
+        if self.heading_ids == HtmlHeadingIds::None {
+            return;
+        }
+
+        let headlines = node.descendants().filter_map(Headline::cast);
+        let mut used: HashSet<String> = HashSet::new();
+
+        // custom ids are reserved first, so slugs never take them
+        let custom_ids = headlines
+            .map(|headline| {
+                let custom_id = (self.heading_ids == HtmlHeadingIds::CustomIdOrSlug)
+                    .then(|| headline.properties()?.get("CUSTOM_ID"))
+                    .flatten()
+                    .map(|id| id.trim().to_string())
+                    .filter(|id| !id.is_empty());
+                if let Some(id) = &custom_id {
+                    used.insert(id.clone());
+                }
+                (headline, custom_id)
+            })
+            .collect::<Vec<_>>();
+
+        for (headline, custom_id) in custom_ids {
+            let id = custom_id.unwrap_or_else(|| {
+                let mut title = String::new();
+                for elem in headline.title() {
+                    push_plain_text(&mut title, elem);
+                }
+                let slug = slugify(&title);
+                let mut id = slug.clone();
+                let mut suffix = 0;
+                while used.contains(&id) {
+                    suffix += 1;
+                    id = format!("{slug}-{suffix}");
+                }
+                used.insert(id.clone());
+                id
+            });
+            self.headline_ids.insert(headline.syntax, id);
+        }
+    }
+
+    /// Collects coderef labels in source and example blocks
+    fn collect_coderefs(&mut self, node: &SyntaxNode) {
+        let mut last_line_number = 0;
+        for node in node.descendants() {
+            let (switches, value) = if let Some(block) = SourceBlock::cast(node.clone()) {
+                (block.block_switches(), block.value())
+            } else if let Some(block) = ExampleBlock::cast(node) {
+                (block.block_switches(), block.value())
+            } else {
+                continue;
+            };
+
+            for line in code_lines(&switches, &value, &mut last_line_number) {
+                let Some(label) = line.label else { continue };
+                let text = match line.number {
+                    Some(number) if !switches.retain_labels || !switches.use_labels => {
+                        number.to_string()
+                    }
+                    _ => label.to_string(),
+                };
+                self.coderefs.insert(label.to_string(), text);
+            }
+        }
+    }
+
+    /// Numbers captioned figures and tables in document order, and collects
+    /// named elements
+    fn collect_references(&mut self, node: &SyntaxNode) {
+        let (mut figures, mut tables) = (0, 0);
+        for node in node.descendants() {
+            let label = if let Some(table) = OrgTable::cast(node.clone()) {
+                table.caption().map(|_| {
+                    tables += 1;
+                    format!("Table {tables}")
+                })
+            } else if let Some(paragraph) = Paragraph::cast(node.clone()) {
+                (paragraph.caption().is_some() && figure_image(&paragraph).is_some()).then(|| {
+                    figures += 1;
+                    format!("Figure {figures}")
+                })
+            } else {
+                None
+            };
+
+            let name = element_name(&node);
+            match (label, name) {
+                (Some(label), name) => {
+                    if let Some(name) = name {
+                        self.references.entry(name).or_insert(label.clone());
+                    }
+                    self.captions.insert(node, label);
+                }
+                (None, Some(name)) => {
+                    self.references.entry(name.clone()).or_insert(name.clone());
+                    self.anchors.insert(node, name);
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    /// Writes `id` attribute if element is named
+    fn push_id(&mut self, name: Option<AffiliatedKeyword>) {
+        if let Some(name) = name.and_then(|n| n.value()) {
+            let _ = write!(&mut self.output, r#" id="{}""#, HtmlEscape(name.trim()));
+        }
+    }
+
+    /// Writes code lines with line numbers and coderef labels
+    fn push_code(&mut self, switches: &BlockSwitches, value: &str) {
+        let lines = code_lines(switches, value, &mut self.last_line_number);
+        let width = lines
+            .last()
+            .and_then(|l| l.number)
+            .map_or(0, |n| n.to_string().len());
+
+        for line in lines {
+            if let Some(number) = line.number {
+                let _ = write!(
+                    &mut self.output,
+                    r#"<span class="linenr">{number:>width$}: </span>"#
+                );
+            }
+            if let Some(label) = line.label {
+                let _ = write!(
+                    &mut self.output,
+                    r#"<span id="coderef-{}" class="coderef-off">{}</span>"#,
+                    HtmlEscape(label),
+                    HtmlEscape(line.text)
+                );
+            } else {
+                let _ = write!(&mut self.output, "{}", HtmlEscape(line.text));
+            }
+            if line.newline {
+                self.output.push('\n');
+            }
+        }
+    }
+
     /// Render syntax node to html string
     ///
     /// ```rust
@@ -91,251 +505,663 @@ impl HtmlExport {
     }
 }
 
-impl Traverser for HtmlExport {
-    fn event(&mut self, event: Event, ctx: &mut TraversalContext) {
-        match event {
-            Event::Enter(Container::Document(_)) => self.output += "<main>",
-            Event::Leave(Container::Document(_)) => self.output += "</main>",
+/// Writes caption label and caption objects
+fn push_caption<H: HtmlHandler + ?Sized>(
+    handler: &mut H,
+    label: &str,
+    caption: Option<AffiliatedKeyword>,
+    ctx: &mut TraversalContext,
+) {
+    let _ = write!(&mut handler.export().output, "{label}:");
+    for elem in caption.into_iter().flat_map(|c| c.objects()) {
+        handler.element(elem, ctx);
+    }
+}
 
-            Event::Enter(Container::Headline(headline)) => {
-                let level = min(headline.level(), 6);
-                let _ = write!(&mut self.output, "<h{level}>");
-                for elem in headline.title() {
-                    self.element(elem, ctx);
-                }
-                let _ = write!(&mut self.output, "</h{level}>");
+/// Renders org elements to html, one method per element
+///
+/// [`HtmlExport`] implements this trait with the default rendering. To
+/// change how some elements are rendered, wrap an `HtmlExport` in a struct,
+/// implement this trait and override only those methods, then forward
+/// [`Traverser::event`] to [`HtmlHandler::html_event`]. Nested elements, like
+/// objects in headline titles, are rendered through the wrapper as well.
+///
+/// Methods prefixed with `enter_` and `leave_` are called on
+/// [`Event::Enter`] and [`Event::Leave`] respectively. Like in
+/// [`Traverser`], calling `ctx.skip()` in an `enter_` method skips the
+/// element's descendants and its `leave_` method.
+///
+/// ```rust
+/// use orgize::{
+///     ast::Code,
+///     export::{HtmlExport, HtmlHandler, TraversalContext, Traverser, Event},
+///     Org,
+/// };
+///
+/// #[derive(Default)]
+/// struct CopyButton(HtmlExport);
+///
+/// impl HtmlHandler for CopyButton {
+///     fn export(&mut self) -> &mut HtmlExport {
+///         &mut self.0
+///     }
+///
+///     fn leave_code(&mut self, _: &Code, _: &mut TraversalContext) {
+///         self.0.push_str("</code><button>copy</button>");
+///     }
+/// }
+///
+/// impl Traverser for CopyButton {
+///     fn event(&mut self, event: Event, ctx: &mut TraversalContext) {
+///         self.html_event(event, ctx)
+///     }
+/// }
+///
+/// let mut handler = CopyButton::default();
+/// Org::parse("* run ~make~\n*bold* ~code~").traverse(&mut handler);
+/// assert_eq!(
+///     handler.0.finish(),
+///     "<main><h1>run <code>make</code><button>copy</button></h1>\
+///     <section><p><b>bold</b> <code>code</code><button>copy</button></p></section></main>"
+/// );
+/// ```
+pub trait HtmlHandler: Traverser {
+    /// Returns the underlying exporter, which holds the output and states
+    fn export(&mut self) -> &mut HtmlExport;
+
+    /// Dispatches event to the method of its element
+    ///
+    /// Named elements without caption are preceded by an anchor, like
+    /// `<a id="name"></a>`, so links to them can be resolved.
+    fn html_event(&mut self, event: Event, ctx: &mut TraversalContext) {
+        if let Event::Enter(container) = &event {
+            let export = self.export();
+            if let Some(name) = element_syntax(container).and_then(|n| export.anchors.get(n)) {
+                let _ = write!(&mut export.output, r#"<a id="{}"></a>"#, HtmlEscape(name));
             }
-            Event::Leave(Container::Headline(_)) => {}
+        }
+
+        match event {
+            Event::Enter(Container::Document(x)) => self.enter_document(&x, ctx),
+            Event::Leave(Container::Document(x)) => self.leave_document(&x, ctx),
+            Event::Enter(Container::Headline(x)) => self.enter_headline(&x, ctx),
+            Event::Leave(Container::Headline(x)) => self.leave_headline(&x, ctx),
+            Event::Enter(Container::Section(x)) => self.enter_section(&x, ctx),
+            Event::Leave(Container::Section(x)) => self.leave_section(&x, ctx),
+            Event::Enter(Container::Paragraph(x)) => self.enter_paragraph(&x, ctx),
+            Event::Leave(Container::Paragraph(x)) => self.leave_paragraph(&x, ctx),
+            Event::Enter(Container::Bold(x)) => self.enter_bold(&x, ctx),
+            Event::Leave(Container::Bold(x)) => self.leave_bold(&x, ctx),
+            Event::Enter(Container::Italic(x)) => self.enter_italic(&x, ctx),
+            Event::Leave(Container::Italic(x)) => self.leave_italic(&x, ctx),
+            Event::Enter(Container::Strike(x)) => self.enter_strike(&x, ctx),
+            Event::Leave(Container::Strike(x)) => self.leave_strike(&x, ctx),
+            Event::Enter(Container::Underline(x)) => self.enter_underline(&x, ctx),
+            Event::Leave(Container::Underline(x)) => self.leave_underline(&x, ctx),
+            Event::Enter(Container::Verbatim(x)) => self.enter_verbatim(&x, ctx),
+            Event::Leave(Container::Verbatim(x)) => self.leave_verbatim(&x, ctx),
+            Event::Enter(Container::Code(x)) => self.enter_code(&x, ctx),
+            Event::Leave(Container::Code(x)) => self.leave_code(&x, ctx),
+            Event::Enter(Container::Subscript(x)) => self.enter_subscript(&x, ctx),
+            Event::Leave(Container::Subscript(x)) => self.leave_subscript(&x, ctx),
+            Event::Enter(Container::Superscript(x)) => self.enter_superscript(&x, ctx),
+            Event::Leave(Container::Superscript(x)) => self.leave_superscript(&x, ctx),
+            Event::Enter(Container::SourceBlock(x)) => self.source_block(&x, ctx),
+            Event::Enter(Container::ExampleBlock(x)) => self.example_block(&x, ctx),
+            Event::Enter(Container::QuoteBlock(x)) => self.enter_quote_block(&x, ctx),
+            Event::Leave(Container::QuoteBlock(x)) => self.leave_quote_block(&x, ctx),
+            Event::Enter(Container::VerseBlock(x)) => self.enter_verse_block(&x, ctx),
+            Event::Leave(Container::VerseBlock(x)) => self.leave_verse_block(&x, ctx),
+            Event::Enter(Container::CenterBlock(x)) => self.enter_center_block(&x, ctx),
+            Event::Leave(Container::CenterBlock(x)) => self.leave_center_block(&x, ctx),
+            Event::Enter(Container::CommentBlock(x)) => self.enter_comment_block(&x, ctx),
+            Event::Leave(Container::CommentBlock(x)) => self.leave_comment_block(&x, ctx),
+            Event::Enter(Container::Comment(x)) => self.enter_comment(&x, ctx),
+            Event::Leave(Container::Comment(x)) => self.leave_comment(&x, ctx),
+            Event::Enter(Container::List(x)) => self.enter_list(&x, ctx),
+            Event::Leave(Container::List(x)) => self.leave_list(&x, ctx),
+            Event::Enter(Container::ListItem(x)) => self.enter_list_item(&x, ctx),
+            Event::Leave(Container::ListItem(x)) => self.leave_list_item(&x, ctx),
+            Event::Enter(Container::OrgTable(x)) => self.enter_org_table(&x, ctx),
+            Event::Leave(Container::OrgTable(x)) => self.leave_org_table(&x, ctx),
+            Event::Enter(Container::OrgTableRow(x)) => self.enter_org_table_row(&x, ctx),
+            Event::Leave(Container::OrgTableRow(x)) => self.leave_org_table_row(&x, ctx),
+            Event::Enter(Container::OrgTableCell(x)) => self.enter_org_table_cell(&x, ctx),
+            Event::Leave(Container::OrgTableCell(x)) => self.leave_org_table_cell(&x, ctx),
+            Event::Enter(Container::Link(x)) => self.enter_link(&x, ctx),
+            Event::Leave(Container::Link(x)) => self.leave_link(&x, ctx),
+            Event::Enter(Container::Keyword(x)) => self.keyword(&x, ctx),
+            Event::Enter(Container::Drawer(x)) => self.drawer(&x, ctx),
+            Event::Enter(Container::PropertyDrawer(x)) => self.property_drawer(&x, ctx),
+            Event::Text(x) => self.text(&x, ctx),
+            Event::LineBreak(x) => self.line_break(&x, ctx),
+            Event::Snippet(x) => self.snippet(&x, ctx),
+            Event::Rule(x) => self.rule(&x, ctx),
+            Event::Timestamp(x) => self.timestamp(&x, ctx),
+            Event::LatexFragment(x) => self.latex_fragment(&x, ctx),
+            Event::LatexEnvironment(x) => self.latex_environment(&x, ctx),
+            Event::Entity(x) => self.entity(&x, ctx),
+            _ => {}
+        }
+    }
 
-            Event::Enter(Container::Paragraph(_)) => self.output += "<p>",
-            Event::Leave(Container::Paragraph(_)) => self.output += "</p>",
+    fn enter_document(&mut self, document: &Document, _: &mut TraversalContext) {
+        let export = self.export();
+        // state collected from a previous document must not leak into this one
+        export.last_line_number = 0;
+        export.coderefs.clear();
+        export.captions.clear();
+        export.references.clear();
+        export.anchors.clear();
+        export.headline_ids.clear();
+        export.link_abbreviations = document.link_abbreviations();
+        export.collect_coderefs(document.syntax());
+        export.collect_references(document.syntax());
+        export.collect_headline_ids(document.syntax());
+        export.read_options(document);
+        export.output += "<main>";
+    }
+    fn leave_document(&mut self, _: &Document, _: &mut TraversalContext) {
+        self.export().output += "</main>";
+    }
 
-            Event::Enter(Container::Section(_)) => self.output += "<section>",
-            Event::Leave(Container::Section(_)) => self.output += "</section>",
+    fn enter_headline(&mut self, headline: &Headline, ctx: &mut TraversalContext) {
+        let level = min(headline.level(), 6);
+        let export = self.export();
+        let _ = write!(&mut export.output, "<h{level}");
+        if let Some(id) = export.headline_ids.get(headline.syntax()) {
+            let _ = write!(&mut export.output, r#" id="{}""#, HtmlEscape(id));
+        }
+        export.output += ">";
+        for elem in headline.title() {
+            self.element(elem, ctx);
+        }
+        let _ = write!(&mut self.export().output, "</h{level}>");
+    }
+    fn leave_headline(&mut self, _: &Headline, _: &mut TraversalContext) {}
 
-            Event::Enter(Container::Italic(_)) => self.output += "<i>",
-            Event::Leave(Container::Italic(_)) => self.output += "</i>",
+    fn enter_section(&mut self, _: &Section, _: &mut TraversalContext) {
+        self.export().output += "<section>";
+    }
+    fn leave_section(&mut self, _: &Section, _: &mut TraversalContext) {
+        self.export().output += "</section>";
+    }
+
+    /// Renders captioned images as `<figure>`
+    fn enter_paragraph(&mut self, paragraph: &Paragraph, ctx: &mut TraversalContext) {
+        let Some(label) = self.export().captions.get(&paragraph.syntax).cloned() else {
+            let export = self.export();
+            export.output += "<p>";
+            export.paragraph_end = export
+                .preserve_breaks
+                .then(|| {
+                    paragraph
+                        .syntax
+                        .children_with_tokens()
+                        .filter(|e| e.kind() != SyntaxKind::BLANK_LINE)
+                        .last()
+                })
+                .flatten()
+                .map(|e| e.text_range().end());
+            return;
+        };
+        self.export().output += "<figure";
+        self.export().push_id(paragraph.name());
+        self.export().output += ">";
+        if let Some(link) = figure_image(paragraph) {
+            self.element(SyntaxElement::Node(link.syntax), ctx);
+        }
+        self.export().output += "<figcaption>";
+        push_caption(self, &label, paragraph.caption(), ctx);
+        self.export().output += "</figcaption></figure>";
+        ctx.skip();
+    }
+    fn leave_paragraph(&mut self, _: &Paragraph, _: &mut TraversalContext) {
+        let export = self.export();
+        export.output += "</p>";
+        export.paragraph_end = None;
+    }
+
+    fn enter_bold(&mut self, _: &Bold, _: &mut TraversalContext) {
+        self.export().output += "<b>";
+    }
+    fn leave_bold(&mut self, _: &Bold, _: &mut TraversalContext) {
+        self.export().output += "</b>";
+    }
 
-            Event::Enter(Container::Bold(_)) => self.output += "<b>",
-            Event::Leave(Container::Bold(_)) => self.output += "</b>",
+    fn enter_italic(&mut self, _: &Italic, _: &mut TraversalContext) {
+        self.export().output += "<i>";
+    }
+    fn leave_italic(&mut self, _: &Italic, _: &mut TraversalContext) {
+        self.export().output += "</i>";
+    }
 
-            Event::Enter(Container::Strike(_)) => self.output += "<s>",
-            Event::Leave(Container::Strike(_)) => self.output += "</s>",
+    fn enter_strike(&mut self, _: &Strike, _: &mut TraversalContext) {
+        self.export().output += "<s>";
+    }
+    fn leave_strike(&mut self, _: &Strike, _: &mut TraversalContext) {
+        self.export().output += "</s>";
+    }
 
-            Event::Enter(Container::Underline(_)) => self.output += "<u>",
-            Event::Leave(Container::Underline(_)) => self.output += "</u>",
+    fn enter_underline(&mut self, _: &Underline, _: &mut TraversalContext) {
+        self.export().output += "<u>";
+    }
+    fn leave_underline(&mut self, _: &Underline, _: &mut TraversalContext) {
+        self.export().output += "</u>";
+    }
 
-            Event::Enter(Container::Verbatim(_)) => self.output += "<code>",
-            Event::Leave(Container::Verbatim(_)) => self.output += "</code>",
+    fn enter_verbatim(&mut self, _: &Verbatim, _: &mut TraversalContext) {
+        self.export().output += "<code>";
+    }
+    fn leave_verbatim(&mut self, _: &Verbatim, _: &mut TraversalContext) {
+        self.export().output += "</code>";
+    }
 
-            Event::Enter(Container::Code(_)) => self.output += "<code>",
-            Event::Leave(Container::Code(_)) => self.output += "</code>",
+    fn enter_code(&mut self, _: &Code, _: &mut TraversalContext) {
+        self.export().output += "<code>";
+    }
+    fn leave_code(&mut self, _: &Code, _: &mut TraversalContext) {
+        self.export().output += "</code>";
+    }
 
-            Event::Enter(Container::SourceBlock(block)) => {
-                if let Some(language) = block.language() {
-                    let _ = write!(
-                        &mut self.output,
-                        r#"<pre><code class="language-{}">"#,
-                        HtmlEscape(&language)
-                    );
-                } else {
-                    self.output += r#"<pre><code>"#
-                }
-            }
-            Event::Leave(Container::SourceBlock(_)) => self.output += "</code></pre>",
+    /// Renders as plain text if disabled by `^:` in `#+OPTIONS`
+    fn enter_subscript(&mut self, subscript: &Subscript, ctx: &mut TraversalContext) {
+        let export = self.export();
+        if export.is_literal_script(subscript.is_braced()) {
+            let _ = write!(&mut export.output, "{}", HtmlEscape(subscript.raw()));
+            ctx.skip();
+        } else {
+            export.output += "<sub>";
+        }
+    }
+    fn leave_subscript(&mut self, _: &Subscript, _: &mut TraversalContext) {
+        self.export().output += "</sub>";
+    }
 
-            Event::Enter(Container::QuoteBlock(_)) => self.output += "<blockquote>",
-            Event::Leave(Container::QuoteBlock(_)) => self.output += "</blockquote>",
+    /// Renders as plain text if disabled by `^:` in `#+OPTIONS`
+    fn enter_superscript(&mut self, superscript: &Superscript, ctx: &mut TraversalContext) {
+        let export = self.export();
+        if export.is_literal_script(superscript.is_braced()) {
+            let _ = write!(&mut export.output, "{}", HtmlEscape(superscript.raw()));
+            ctx.skip();
+        } else {
+            export.output += "<sup>";
+        }
+    }
+    fn leave_superscript(&mut self, _: &Superscript, _: &mut TraversalContext) {
+        self.export().output += "</sup>";
+    }
 
-            Event::Enter(Container::VerseBlock(_)) => self.output += "<p class=\"verse\">",
-            Event::Leave(Container::VerseBlock(_)) => self.output += "</p>",
+    /// Renders the whole block, descendants are skipped
+    fn source_block(&mut self, block: &SourceBlock, ctx: &mut TraversalContext) {
+        let export = self.export();
+        if let Some(language) = block.language() {
+            let _ = write!(
+                &mut export.output,
+                r#"<pre><code class="language-{}">"#,
+                HtmlEscape(&language)
+            );
+        } else {
+            export.output += r#"<pre><code>"#
+        }
+        export.push_code(&block.block_switches(), &block.value());
+        export.output += "</code></pre>";
+        ctx.skip();
+    }
 
-            Event::Enter(Container::ExampleBlock(_)) => self.output += "<pre class=\"example\">",
-            Event::Leave(Container::ExampleBlock(_)) => self.output += "</pre>",
+    /// Renders the whole block, descendants are skipped
+    fn example_block(&mut self, block: &ExampleBlock, ctx: &mut TraversalContext) {
+        let export = self.export();
+        export.output += "<pre class=\"example\">";
+        export.push_code(&block.block_switches(), &block.value());
+        export.output += "</pre>";
+        ctx.skip();
+    }
 
-            Event::Enter(Container::CenterBlock(_)) => self.output += "<div class=\"center\">",
-            Event::Leave(Container::CenterBlock(_)) => self.output += "</div>",
+    fn enter_quote_block(&mut self, _: &QuoteBlock, _: &mut TraversalContext) {
+        self.export().output += "<blockquote>";
+    }
+    fn leave_quote_block(&mut self, _: &QuoteBlock, _: &mut TraversalContext) {
+        self.export().output += "</blockquote>";
+    }
 
-            Event::Enter(Container::CommentBlock(_)) => self.output += "<!--",
-            Event::Leave(Container::CommentBlock(_)) => self.output += "-->",
+    fn enter_verse_block(&mut self, _: &VerseBlock, _: &mut TraversalContext) {
+        self.export().output += "<p class=\"verse\">";
+    }
+    fn leave_verse_block(&mut self, _: &VerseBlock, _: &mut TraversalContext) {
+        self.export().output += "</p>";
+    }
 
-            Event::Enter(Container::Comment(_)) => self.output += "<!--",
-            Event::Leave(Container::Comment(_)) => self.output += "-->",
+    fn enter_center_block(&mut self, _: &CenterBlock, _: &mut TraversalContext) {
+        self.export().output += "<div class=\"center\">";
+    }
+    fn leave_center_block(&mut self, _: &CenterBlock, _: &mut TraversalContext) {
+        self.export().output += "</div>";
+    }
 
-            Event::Enter(Container::Subscript(_)) => self.output += "<sub>",
-            Event::Leave(Container::Subscript(_)) => self.output += "</sub>",
+    fn enter_comment_block(&mut self, _: &CommentBlock, _: &mut TraversalContext) {
+        self.export().output += "<!--";
+    }
+    fn leave_comment_block(&mut self, _: &CommentBlock, _: &mut TraversalContext) {
+        self.export().output += "-->";
+    }
 
-            Event::Enter(Container::Superscript(_)) => self.output += "<sup>",
-            Event::Leave(Container::Superscript(_)) => self.output += "</sup>",
+    fn enter_comment(&mut self, _: &Comment, _: &mut TraversalContext) {
+        self.export().output += "<!--";
+    }
+    fn leave_comment(&mut self, _: &Comment, _: &mut TraversalContext) {
+        self.export().output += "-->";
+    }
 
-            Event::Enter(Container::List(list)) => {
-                self.output += if list.is_ordered() {
-                    self.in_descriptive_list.push(false);
-                    "<ol>"
-                } else if list.is_descriptive() {
-                    self.in_descriptive_list.push(true);
-                    "<dl>"
-                } else {
-                    self.in_descriptive_list.push(false);
-                    "<ul>"
-                };
-            }
-            Event::Leave(Container::List(list)) => {
-                self.output += if list.is_ordered() {
-                    "</ol>"
-                } else if let Some(true) = self.in_descriptive_list.last() {
-                    "</dl>"
-                } else {
-                    "</ul>"
-                };
-                self.in_descriptive_list.pop();
+    fn enter_list(&mut self, list: &List, _: &mut TraversalContext) {
+        let export = self.export();
+        export.output += if list.is_ordered() {
+            export.in_descriptive_list.push(false);
+            match list.items().next().and_then(|i| i.bullet().chars().next()) {
+                Some(c) if c.is_ascii_lowercase() => "<ol type=\"a\">",
+                Some(c) if c.is_ascii_uppercase() => "<ol type=\"A\">",
+                _ => "<ol>",
             }
-            Event::Enter(Container::ListItem(list_item)) => {
-                if let Some(&true) = self.in_descriptive_list.last() {
-                    self.output += "<dt>";
-                    for elem in list_item.tag() {
-                        self.element(elem, ctx);
-                    }
-                    self.output += "</dt><dd>";
-                } else {
-                    self.output += "<li>";
-                }
+        } else if list.is_descriptive() {
+            export.in_descriptive_list.push(true);
+            "<dl>"
+        } else {
+            export.in_descriptive_list.push(false);
+            "<ul>"
+        };
+    }
+    fn leave_list(&mut self, list: &List, _: &mut TraversalContext) {
+        let export = self.export();
+        export.output += if list.is_ordered() {
+            "</ol>"
+        } else if let Some(true) = export.in_descriptive_list.last() {
+            "</dl>"
+        } else {
+            "</ul>"
+        };
+        export.in_descriptive_list.pop();
+    }
+
+    fn enter_list_item(&mut self, list_item: &ListItem, ctx: &mut TraversalContext) {
+        if let Some(&true) = self.export().in_descriptive_list.last() {
+            self.export().output += "<dt>";
+            for elem in list_item.tag() {
+                self.element(elem, ctx);
             }
-            Event::Leave(Container::ListItem(_)) => {
-                if let Some(&true) = self.in_descriptive_list.last() {
-                    self.output += "</dd>";
-                } else {
-                    self.output += "</li>";
-                }
+            self.export().output += "</dt><dd>";
+        } else {
+            self.export().output += "<li>";
+        }
+    }
+    fn leave_list_item(&mut self, _: &ListItem, _: &mut TraversalContext) {
+        let export = self.export();
+        if let Some(&true) = export.in_descriptive_list.last() {
+            export.output += "</dd>";
+        } else {
+            export.output += "</li>";
+        }
+    }
+
+    fn enter_org_table(&mut self, table: &OrgTable, ctx: &mut TraversalContext) {
+        match self.export().captions.get(&table.syntax).cloned() {
+            Some(label) => {
+                self.export().output += "<table";
+                self.export().push_id(table.name());
+                self.export().output += "><caption>";
+                push_caption(self, &label, table.caption(), ctx);
+                self.export().output += "</caption>";
             }
+            None => self.export().output += "<table>",
+        }
+        self.export().table_row = if table.has_header() {
+            TableRow::HeaderRule
+        } else {
+            TableRow::BodyRule
+        };
+        self.export().table_alignments = table.column_alignments();
+    }
+    fn leave_org_table(&mut self, _: &OrgTable, _: &mut TraversalContext) {
+        let export = self.export();
+        match export.table_row {
+            TableRow::Body => export.output += "</tbody>",
+            TableRow::Header => export.output += "</thead>",
+            _ => {}
+        }
+        export.output += "</table>";
+    }
 
-            Event::Enter(Container::OrgTable(table)) => {
-                self.output += "<table>";
-                self.table_row = if table.has_header() {
-                    TableRow::HeaderRule
-                } else {
-                    TableRow::BodyRule
+    fn enter_org_table_row(&mut self, row: &OrgTableRow, ctx: &mut TraversalContext) {
+        let export = self.export();
+        // alignment cookies are applied to cells, not rendered
+        if row.alignments().is_some() {
+            return ctx.skip();
+        }
+        if row.is_rule() {
+            match export.table_row {
+                TableRow::Body => {
+                    export.output += "</tbody>";
+                    export.table_row = TableRow::BodyRule;
                 }
-            }
-            Event::Leave(Container::OrgTable(_)) => {
-                match self.table_row {
-                    TableRow::Body => self.output += "</tbody>",
-                    TableRow::Header => self.output += "</thead>",
-                    _ => {}
+                TableRow::Header => {
+                    export.output += "</thead>";
+                    export.table_row = TableRow::BodyRule;
                 }
-                self.output += "</table>";
+                _ => {}
             }
-            Event::Enter(Container::OrgTableRow(row)) => {
-                if row.is_rule() {
-                    match self.table_row {
-                        TableRow::Body => {
-                            self.output += "</tbody>";
-                            self.table_row = TableRow::BodyRule;
-                        }
-                        TableRow::Header => {
-                            self.output += "</thead>";
-                            self.table_row = TableRow::BodyRule;
-                        }
-                        _ => {}
-                    }
-                    ctx.skip();
-                } else {
-                    match self.table_row {
-                        TableRow::HeaderRule => {
-                            self.table_row = TableRow::Header;
-                            self.output += "<thead>";
-                        }
-                        TableRow::BodyRule => {
-                            self.table_row = TableRow::Body;
-                            self.output += "<tbody>";
-                        }
-                        _ => {}
-                    }
-                    self.output += "<tr>";
+            ctx.skip();
+        } else {
+            match export.table_row {
+                TableRow::HeaderRule => {
+                    export.table_row = TableRow::Header;
+                    export.output += "<thead>";
                 }
+                TableRow::BodyRule => {
+                    export.table_row = TableRow::Body;
+                    export.output += "<tbody>";
+                }
+                _ => {}
             }
-            Event::Leave(Container::OrgTableRow(row)) => {
-                if row.is_rule() {
-                    match self.table_row {
-                        TableRow::Body => {
-                            self.output += "</tbody>";
-                            self.table_row = TableRow::BodyRule;
-                        }
-                        TableRow::Header => {
-                            self.output += "</thead>";
-                            self.table_row = TableRow::BodyRule;
-                        }
-                        _ => {}
-                    }
-                    ctx.skip();
-                } else {
-                    self.output += "</tr>";
+            export.output += "<tr>";
+        }
+    }
+    fn leave_org_table_row(&mut self, row: &OrgTableRow, ctx: &mut TraversalContext) {
+        let export = self.export();
+        if row.alignments().is_some() {
+            return;
+        }
+        if row.is_rule() {
+            match export.table_row {
+                TableRow::Body => {
+                    export.output += "</tbody>";
+                    export.table_row = TableRow::BodyRule;
                 }
+                TableRow::Header => {
+                    export.output += "</thead>";
+                    export.table_row = TableRow::BodyRule;
+                }
+                _ => {}
+            }
+            ctx.skip();
+        } else {
+            export.output += "</tr>";
+        }
+    }
+
+    fn enter_org_table_cell(&mut self, cell: &OrgTableCell, _: &mut TraversalContext) {
+        let export = self.export();
+        let column = cell
+            .syntax
+            .siblings_with_tokens(Direction::Prev)
+            .filter(|e| e.kind() == SyntaxKind::PIPE)
+            .count()
+            .saturating_sub(1);
+        match export.table_alignments.get(column).copied().flatten() {
+            Some(ColumnAlign::Left) => export.output += r#"<td class="org-left">"#,
+            Some(ColumnAlign::Center) => export.output += r#"<td class="org-center">"#,
+            Some(ColumnAlign::Right) => export.output += r#"<td class="org-right">"#,
+            None => export.output += "<td>",
+        }
+    }
+    fn leave_org_table_cell(&mut self, _: &OrgTableCell, _: &mut TraversalContext) {
+        self.export().output += "</td>";
+    }
+
+    fn enter_link(&mut self, link: &Link, ctx: &mut TraversalContext) {
+        let export = self.export();
+        let path = link.path();
+        if let Some(label) = path.strip_prefix('(').and_then(|p| p.strip_suffix(')')) {
+            let _ = write!(
+                &mut export.output,
+                r##"<a href="#coderef-{}">"##,
+                HtmlEscape(label)
+            );
+            if !link.has_description() {
+                let text = export.coderefs.get(label).map_or(label, |t| t.as_str());
+                let _ = write!(&mut export.output, "{}</a>", HtmlEscape(text));
+                ctx.skip();
             }
-            Event::Enter(Container::OrgTableCell(_)) => self.output += "<td>",
-            Event::Leave(Container::OrgTableCell(_)) => self.output += "</td>",
+            return;
+        }
 
-            Event::Enter(Container::Link(link)) => {
-                let path = link.path();
-                let path = path.trim_start_matches("file:");
+        if let Some(label) = export.references.get(path.as_ref()) {
+            let _ = write!(&mut export.output, r##"<a href="#{}">"##, HtmlEscape(&path));
+            if !link.has_description() {
+                let _ = write!(&mut export.output, "{}</a>", HtmlEscape(label));
+                ctx.skip();
+            }
+            return;
+        }
 
-                if link.is_image() {
-                    let _ = write!(&mut self.output, r#"<img src="{}">"#, HtmlEscape(&path));
-                    return ctx.skip();
-                }
+        let path = link.expand(&export.link_abbreviations);
+        let path = path.trim_start_matches("file:");
 
-                let _ = write!(&mut self.output, r#"<a href="{}">"#, HtmlEscape(&path));
+        if link.is_image() {
+            let _ = write!(&mut export.output, r#"<img src="{}">"#, HtmlEscape(&path));
+            return ctx.skip();
+        }
 
-                if !link.has_description() {
-                    let _ = write!(&mut self.output, "{}</a>", HtmlEscape(&path));
-                    ctx.skip();
+        let _ = write!(&mut export.output, r#"<a href="{}">"#, HtmlEscape(&path));
+
+        if !link.has_description() {
+            let _ = write!(&mut export.output, "{}</a>", HtmlEscape(&path));
+            ctx.skip();
+        }
+    }
+    fn leave_link(&mut self, _: &Link, _: &mut TraversalContext) {
+        self.export().output += "</a>";
+    }
+
+    /// Keywords are ignored
+    fn keyword(&mut self, _: &Keyword, ctx: &mut TraversalContext) {
+        ctx.skip();
+    }
+
+    /// Renders the drawer if allowed by [`HtmlExport::with_drawers`],
+    /// descendants are skipped
+    fn drawer(&mut self, drawer: &Drawer, ctx: &mut TraversalContext) {
+        if self.export().drawers.is_rendered(&drawer.name()) {
+            self.export().output += r#"<div class="drawer">"#;
+            if let Some(content) = drawer
+                .syntax
+                .children()
+                .find(|n| n.kind() == SyntaxKind::DRAWER_CONTENT)
+            {
+                for child in content.children_with_tokens() {
+                    self.element(child, ctx);
                 }
             }
-            Event::Leave(Container::Link(_)) => self.output += "</a>",
+            self.export().output += "</div>";
+        }
+        ctx.skip();
+    }
 
-            Event::Text(text) => {
-                let _ = write!(&mut self.output, "{}", HtmlEscape(text));
-            }
+    /// Property drawers are ignored
+    fn property_drawer(&mut self, _: &PropertyDrawer, ctx: &mut TraversalContext) {
+        ctx.skip();
+    }
 
-            Event::LineBreak(_) => self.output += "<br/>",
+    /// Renders newlines in paragraphs as `<br/>` if enabled by `\n:` in
+    /// `#+OPTIONS`
+    fn text(&mut self, text: &Token, _: &mut TraversalContext) {
+        let export = self.export();
+        let text_str = cell_text(text);
 
-            Event::Snippet(snippet) => {
-                if snippet.backend().eq_ignore_ascii_case("html") {
-                    self.output += &snippet.value();
-                }
+        let Some(paragraph_end) = export.paragraph_end else {
+            let _ = write!(&mut export.output, "{}", HtmlEscape(text_str));
+            return;
+        };
+
+        // newline at the end of paragraph isn't a break
+        let (content, trailing) = if text.end() == paragraph_end {
+            let trimmed = text_str.trim_end_matches(['\r', '\n']);
+            (trimmed, &text_str[trimmed.len()..])
+        } else {
+            (&*text_str, "")
+        };
+
+        for (idx, line) in content.split('\n').enumerate() {
+            if idx > 0 {
+                export.output += "<br/>\n";
             }
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let _ = write!(&mut export.output, "{}", HtmlEscape(line));
+        }
+        export.output += trailing;
+    }
 
-            Event::Rule(_) => self.output += "<hr/>",
-
-            Event::Timestamp(timestamp) => {
-                self.output += r#"<span class="timestamp-wrapper"><span class="timestamp">"#;
-                for e in timestamp.syntax.children_with_tokens() {
-                    match e {
-                        NodeOrToken::Token(t) if t.kind() == SyntaxKind::MINUS2 => {
-                            self.output += "&#x2013;";
-                        }
-                        NodeOrToken::Token(t) => {
-                            self.output += t.text();
-                        }
-                        _ => {}
-                    }
+    fn line_break(&mut self, _: &LineBreak, _: &mut TraversalContext) {
+        self.export().output += "<br/>";
+    }
+
+    /// Renders html snippets as is, others are ignored
+    fn snippet(&mut self, snippet: &Snippet, _: &mut TraversalContext) {
+        if snippet.backend().eq_ignore_ascii_case("html") {
+            self.export().output += &snippet.value();
+        }
+    }
+
+    fn rule(&mut self, _: &Rule, _: &mut TraversalContext) {
+        self.export().output += "<hr/>";
+    }
+
+    fn timestamp(&mut self, timestamp: &Timestamp, _: &mut TraversalContext) {
+        let export = self.export();
+        export.output += r#"<span class="timestamp-wrapper"><span class="timestamp">"#;
+        for e in timestamp.syntax.children_with_tokens() {
+            match e {
+                NodeOrToken::Token(t) if t.kind() == SyntaxKind::MINUS2 => {
+                    export.output += "&#x2013;";
+                }
+                NodeOrToken::Token(t) => {
+                    export.output += t.text();
                 }
-                self.output += r#"</span></span>"#;
+                _ => {}
             }
+        }
+        export.output += r#"</span></span>"#;
+    }
 
-            Event::LatexFragment(latex) => {
-                let _ = write!(&mut self.output, "{}", &latex.syntax);
-            }
-            Event::LatexEnvironment(latex) => {
-                let _ = write!(&mut self.output, "{}", &latex.syntax);
-            }
+    fn latex_fragment(&mut self, latex: &LatexFragment, _: &mut TraversalContext) {
+        let _ = write!(&mut self.export().output, "{}", &latex.syntax);
+    }
+
+    fn latex_environment(&mut self, latex: &LatexEnvironment, _: &mut TraversalContext) {
+        let _ = write!(&mut self.export().output, "{}", &latex.syntax);
+    }
 
-            // ignores keyword
-            Event::Enter(Container::Keyword(_)) => ctx.skip(),
+    fn entity(&mut self, entity: &Entity, _: &mut TraversalContext) {
+        self.export().output += entity.html();
+    }
+}
 
-            Event::Entity(entity) => self.output += entity.html(),
+impl HtmlHandler for HtmlExport {
+    fn export(&mut self) -> &mut HtmlExport {
+        self
+    }
+}
 
-            _ => {}
-        }
+impl Traverser for HtmlExport {
+    fn event(&mut self, event: Event, ctx: &mut TraversalContext) {
+        self.html_event(event, ctx)
     }
 }
@@ -1,7 +1,12 @@
 use std::cmp::min;
 use std::fmt::Write as _;
 
-use crate::{SyntaxElement, SyntaxNode};
+use rowan::ast::AstNode;
+
+use crate::{
+    ast::{cell_text, ColumnAlign, OrgTable},
+    SyntaxElement, SyntaxNode,
+};
 
 use super::event::{Container, Event};
 use super::TraversalContext;
@@ -39,6 +44,93 @@ impl MarkdownExport {
         self.output
     }
 
+    /// Renders org table as GFM table
+    ///
+    /// Rules are dropped, and a row of alignment cookies like `<l>`, `<c>`
+    /// or `<r>` becomes the alignment of the delimiter row. If the table
+    /// has no header, an empty one is added, since GFM tables require it.
+    fn table(&mut self, table: &OrgTable) {
+        let mut alignments: Vec<&str> = vec![];
+        let mut rows: Vec<Vec<String>> = vec![];
+        let mut header_len = None;
+
+        for row in table.rows() {
+            if row.is_rule() {
+                if header_len.is_none() && !rows.is_empty() {
+                    header_len = Some(rows.len());
+                }
+                continue;
+            }
+
+            if let Some(cookies) = row.alignments() {
+                alignments = cookies
+                    .into_iter()
+                    .map(|align| match align {
+                        Some(ColumnAlign::Left) => ":---",
+                        Some(ColumnAlign::Center) => ":---:",
+                        Some(ColumnAlign::Right) => "---:",
+                        None => "---",
+                    })
+                    .collect();
+                continue;
+            }
+
+            let cells = row
+                .cells()
+                .map(|cell| {
+                    cell.map(|cell| {
+                        let mut markdown = MarkdownExport::default();
+                        markdown.render(cell.syntax());
+                        let text = markdown.finish();
+                        text.split_whitespace()
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                            .replace('|', "\\|")
+                    })
+                    .unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+
+            rows.push(cells);
+        }
+
+        let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+        if columns == 0 {
+            return;
+        }
+
+        if header_len.is_none_or(|len| len >= rows.len()) {
+            rows.insert(0, vec![]);
+        }
+
+        for (idx, row) in rows.iter().enumerate() {
+            self.output += "|";
+            for column in 0..columns {
+                let cell = row.get(column).map(String::as_str).unwrap_or_default();
+                let _ = write!(&mut self.output, " {cell} |");
+            }
+            self.output += "\n";
+
+            if idx == 0 {
+                self.output += "|";
+                for column in 0..columns {
+                    let alignment = alignments.get(column).copied().unwrap_or("---");
+                    let _ = write!(&mut self.output, " {alignment} |");
+                }
+                self.output += "\n";
+            }
+        }
+    }
+
+    /// Renders contents as fenced code block
+    fn code_block(&mut self, language: &str, value: &str) {
+        self.follows_newline();
+        let _ = writeln!(&mut self.output, "```{language}");
+        self.output += value;
+        self.follows_newline();
+        self.output += "```\n";
+    }
+
     fn follows_newline(&mut self) {
         if !self.output.is_empty() && !self.output.ends_with(['\n', '\r']) {
             self.output += "\n";
@@ -86,13 +178,29 @@ impl Traverser for MarkdownExport {
             | Event::Leave(Container::Code(_)) => self.output += "`",
 
             Event::Enter(Container::SourceBlock(block)) => {
-                self.follows_newline();
-                self.output += "```";
-                if let Some(language) = block.language() {
-                    self.output += &language;
+                let language = block.language();
+                self.code_block(language.as_deref().unwrap_or_default(), &block.value());
+                ctx.skip();
+            }
+            Event::Leave(Container::SourceBlock(_)) => {}
+
+            Event::Enter(Container::ExampleBlock(block)) => {
+                self.code_block("", &block.value());
+                ctx.skip();
+            }
+            Event::Leave(Container::ExampleBlock(_)) => {}
+
+            // only blocks targeting markdown are kept, others are dropped
+            Event::Enter(Container::ExportBlock(block)) => {
+                if block.ty().is_some_and(|ty| {
+                    ty.eq_ignore_ascii_case("markdown") || ty.eq_ignore_ascii_case("md")
+                }) {
+                    self.follows_newline();
+                    self.output += &block.value();
                 }
+                ctx.skip();
             }
-            Event::Leave(Container::SourceBlock(_)) => self.output += "```\n",
+            Event::Leave(Container::ExportBlock(_)) => {}
 
             Event::Enter(Container::QuoteBlock(_)) => {
                 self.inside_blockquote = true;
@@ -107,11 +215,18 @@ impl Traverser for MarkdownExport {
             Event::Enter(Container::Comment(_)) => self.output += "<!--",
             Event::Leave(Container::Comment(_)) => self.output += "-->",
 
-            Event::Enter(Container::Subscript(_)) => self.output += "<sub>",
-            Event::Leave(Container::Subscript(_)) => self.output += "</sub>",
+            // markdown has no sub/superscripts, keep the original text
+            Event::Enter(Container::Subscript(subscript)) => {
+                self.output += &subscript.raw();
+                ctx.skip();
+            }
+            Event::Leave(Container::Subscript(_)) => {}
 
-            Event::Enter(Container::Superscript(_)) => self.output += "<sup>",
-            Event::Leave(Container::Superscript(_)) => self.output += "</sup>",
+            Event::Enter(Container::Superscript(superscript)) => {
+                self.output += &superscript.raw();
+                ctx.skip();
+            }
+            Event::Leave(Container::Superscript(_)) => {}
 
             Event::Enter(Container::List(_list)) => {}
             Event::Leave(Container::List(_list)) => {}
@@ -123,7 +238,11 @@ impl Traverser for MarkdownExport {
             }
             Event::Leave(Container::ListItem(_)) => {}
 
-            Event::Enter(Container::OrgTable(_table)) => {}
+            Event::Enter(Container::OrgTable(table)) => {
+                self.follows_newline();
+                self.table(&table);
+                ctx.skip();
+            }
             Event::Leave(Container::OrgTable(_)) => {}
             Event::Enter(Container::OrgTableRow(_row)) => {}
             Event::Leave(Container::OrgTableRow(_row)) => {}
@@ -159,7 +278,7 @@ impl Traverser for MarkdownExport {
                         self.output += line;
                     }
                 } else {
-                    self.output += &*text;
+                    self.output += &cell_text(&text);
                 }
             }
 
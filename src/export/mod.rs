@@ -3,9 +3,11 @@
 mod event;
 mod html;
 mod markdown;
+mod strip;
 mod traverse;
 
 pub use event::{Container, Event};
-pub use html::{HtmlEscape, HtmlExport};
+pub use html::{HtmlDrawers, HtmlEscape, HtmlExport, HtmlHandler, HtmlHeadingIds};
 pub use markdown::MarkdownExport;
+pub use strip::ExportOptions;
 pub use traverse::{from_fn, from_fn_with_ctx, FromFn, FromFnWithCtx, TraversalContext, Traverser};
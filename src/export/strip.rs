@@ -0,0 +1,163 @@
+use rowan::{ast::AstNode, TextRange, WalkEvent};
+
+use crate::{
+    ast::{Headline, Keyword},
+    syntax::SyntaxKind,
+    Org, SyntaxNode,
+};
+
+/// Keywords which only affect editing, and are removed by
+/// [`Org::stripped_for_export`]
+const NON_EXPORT_KEYWORDS: &[&str] = &[
+    "ARCHIVE",
+    "CATEGORY",
+    "COLUMNS",
+    "CONSTANTS",
+    "DRAWERS",
+    "FILETAGS",
+    "PRIORITIES",
+    "PROPERTY",
+    "SEQ_TODO",
+    "STARTUP",
+    "TAGS",
+    "TODO",
+    "TYP_TODO",
+];
+
+/// Controls what [`Org::stripped_for_export`] keeps
+#[derive(Clone, Debug)]
+pub struct ExportOptions {
+    /// Keep drawers, excluding property drawers
+    ///
+    /// Equivalent to `d:` in `#+OPTIONS`. Defaults to `false`.
+    pub drawers: bool,
+
+    /// Keep property drawers
+    ///
+    /// Equivalent to `prop:` in `#+OPTIONS`. Defaults to `false`.
+    pub property_drawers: bool,
+
+    /// Keep planning lines
+    ///
+    /// Equivalent to `p:` in `#+OPTIONS`. Defaults to `false`.
+    pub planning: bool,
+
+    /// Keep clock lines
+    ///
+    /// Equivalent to `c:` in `#+OPTIONS`. Defaults to `false`.
+    pub clocks: bool,
+
+    /// Keep comments, comment blocks and `COMMENT` headlines
+    ///
+    /// Defaults to `false`.
+    pub comments: bool,
+
+    /// Headlines with any of these tags are removed with their subtrees
+    ///
+    /// Equivalent to `#+EXCLUDE_TAGS`. Defaults to `["noexport"]`.
+    pub exclude_tags: Vec<String>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            drawers: false,
+            property_drawers: false,
+            planning: false,
+            clocks: false,
+            comments: false,
+            exclude_tags: vec!["noexport".into()],
+        }
+    }
+}
+
+impl ExportOptions {
+    fn is_stripped(&self, node: &SyntaxNode) -> bool {
+        match node.kind() {
+            SyntaxKind::DRAWER => !self.drawers,
+            SyntaxKind::PROPERTY_DRAWER => !self.property_drawers,
+            SyntaxKind::PLANNING => !self.planning,
+            SyntaxKind::CLOCK => !self.clocks,
+            SyntaxKind::COMMENT | SyntaxKind::COMMENT_BLOCK => !self.comments,
+            SyntaxKind::KEYWORD => Keyword::cast(node.clone()).is_some_and(|kw| {
+                let key = kw.key();
+                NON_EXPORT_KEYWORDS
+                    .iter()
+                    .any(|k| k.eq_ignore_ascii_case(&key))
+            }),
+            SyntaxKind::HEADLINE => Headline::cast(node.clone()).is_some_and(|hdl| {
+                (!self.comments && hdl.is_commented())
+                    || hdl
+                        .tags()
+                        .any(|tag| self.exclude_tags.iter().any(|t| tag == *t))
+            }),
+            _ => false,
+        }
+    }
+}
+
+impl Org {
+    /// Returns a new `Org` with content that isn't exported removed
+    ///
+    /// Depending on `options`, drawers, planning lines, clocks, comments
+    /// and headlines with excluded tags are pruned, along with keywords
+    /// that only affect editing, like `#+STARTUP` or `#+TODO`. The result
+    /// is parsed with the same config as `self`.
+    ///
+    /// ```rust
+    /// use orgize::{Org, export::ExportOptions};
+    ///
+    /// let org = Org::parse(r#"#+TITLE: doc
+    /// #+STARTUP: overview
+    /// ## a comment
+    /// * TODO a
+    /// SCHEDULED: <2024-01-01 Mon>
+    /// :PROPERTIES:
+    /// :ID: a
+    /// :END:
+    /// :LOGBOOK:
+    /// CLOCK: [2024-01-01 Mon 10:00]--[2024-01-01 Mon 11:00] =>  1:00
+    /// :END:
+    /// text
+    /// ** private :noexport:
+    /// secret
+    /// * COMMENT draft
+    /// * b
+    /// "#);
+    ///
+    /// let stripped = org.stripped_for_export(&ExportOptions::default());
+    /// assert_eq!(stripped.to_org(), "#+TITLE: doc\n* TODO a\ntext\n* b\n");
+    ///
+    /// let options = ExportOptions { property_drawers: true, exclude_tags: vec![], ..Default::default() };
+    /// let stripped = org.stripped_for_export(&options);
+    /// assert_eq!(
+    ///     stripped.to_org(),
+    ///     "#+TITLE: doc\n* TODO a\n:PROPERTIES:\n:ID: a\n:END:\ntext\n** private :noexport:\nsecret\n* b\n"
+    /// );
+    /// ```
+    pub fn stripped_for_export(&self, options: &ExportOptions) -> Org {
+        let root = SyntaxNode::new_root(self.green.clone());
+
+        let mut removed: Vec<TextRange> = vec![];
+        let mut preorder = root.preorder();
+        while let Some(event) = preorder.next() {
+            if let WalkEvent::Enter(node) = event {
+                if options.is_stripped(&node) {
+                    removed.push(node.text_range());
+                    preorder.skip_subtree();
+                }
+            }
+        }
+
+        let text = root.to_string();
+        let mut output = String::with_capacity(text.len());
+        let mut start = 0;
+        for range in removed {
+            output.push_str(&text[start..range.start().into()]);
+            start = range.end().into();
+        }
+        output.push_str(&text[start..]);
+
+        self.base_config.clone().parse(output)
+    }
+}
@@ -29,6 +29,36 @@ impl TraversalContext {
         self.control = TraversalControl::Up;
     }
     /// Skips traversal of the current node's descendants
+    ///
+    /// When called on an enter event, neither the node's descendants nor
+    /// its leave event are visited, and traversal continues with its next
+    /// sibling. It works the same for every container, like headlines,
+    /// drawers and blocks. Events already dispatched are not affected.
+    ///
+    /// When called on a leave event or a standalone event, like
+    /// [`Event::Text`], it has no effect.
+    ///
+    /// ```rust
+    /// use orgize::{
+    ///     export::{from_fn_with_ctx, Container, Event},
+    ///     Org,
+    /// };
+    ///
+    /// let org = Org::parse("* a\ntext a\n* b :ARCHIVE:\ntext b\n** c\ntext c\n* d\ntext d");
+    ///
+    /// let mut texts = vec![];
+    /// let mut leaves = 0;
+    /// let mut handler = from_fn_with_ctx(|event, ctx| match event {
+    ///     Event::Enter(Container::Headline(hdl)) if hdl.is_archived() => ctx.skip(),
+    ///     Event::Leave(Container::Headline(_)) => leaves += 1,
+    ///     Event::Text(text) => texts.push(text.trim().to_string()),
+    ///     _ => {}
+    /// });
+    /// org.traverse(&mut handler);
+    ///
+    /// assert_eq!(texts, vec!["text a", "text d"]);
+    /// assert_eq!(leaves, 2);
+    /// ```
     pub fn skip(&mut self) {
         self.control = TraversalControl::Skip;
     }
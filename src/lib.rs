@@ -1,21 +1,40 @@
 #![doc = include_str!("../README.md")]
 
 pub mod ast;
+pub mod builder;
+mod compare;
 pub mod config;
 mod entities;
+pub mod escape;
 pub mod export;
+mod normalize;
 mod org;
+mod read;
+mod recovery;
 mod replace;
+mod resolve;
+mod semantic;
+#[cfg(feature = "serde")]
+mod serialize;
 mod syntax;
 #[cfg(test)]
 mod tests;
+pub mod width;
 
 // Re-export of the rowan crate.
 pub use rowan;
 
+pub use builder::OrgBuilder;
+pub use compare::CompareOptions;
 pub use config::ParseConfig;
-pub use org::Org;
+pub use normalize::NormalizeOptions;
+pub use org::{ElementParser, Org, WalkAction};
+pub use read::ReadError;
+pub use resolve::{LinkTarget, ResolveOptions};
 pub use rowan::{TextRange, TextSize};
+pub use semantic::{SemanticToken, SemanticTokenType};
+#[cfg(feature = "serde")]
+pub use serialize::SerializeTree;
 pub use syntax::{
     SyntaxElement, SyntaxElementChildren, SyntaxKind, SyntaxNode, SyntaxNodeChildren, SyntaxToken,
 };
@@ -0,0 +1,147 @@
+use rowan::{ast::AstNode, NodeOrToken, WalkEvent};
+
+use crate::{ast::OrgTable, syntax::SyntaxKind, Org, SyntaxNode, SyntaxToken};
+
+/// Controls how [`Org::to_org_normalized_with`] rewrites the document
+#[derive(Clone, Debug)]
+pub struct NormalizeOptions {
+    /// Align columns of tables, see [`OrgTable::align`]
+    ///
+    /// Defaults to `true`.
+    pub align_tables: bool,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        NormalizeOptions { align_tables: true }
+    }
+}
+
+impl Org {
+    /// Returns org-mode string with normalized whitespace, using default
+    /// options
+    ///
+    /// Equivalent to `to_org_normalized_with(&NormalizeOptions::default())`.
+    pub fn to_org_normalized(&self) -> String {
+        self.to_org_normalized_with(&NormalizeOptions::default())
+    }
+
+    /// Returns org-mode string with normalized whitespace
+    ///
+    /// Unlike [`Org::to_org`], the output isn't lossless:
+    ///
+    /// - consecutive blank lines are collapsed into one, except inside
+    ///   lists, where two blank lines end the list
+    /// - blank lines only containing spaces or tabs are emptied
+    /// - headline stars are followed by a single space
+    /// - table columns are aligned, if [`NormalizeOptions::align_tables`]
+    ///   is `true`
+    ///
+    /// Contents of blocks, like `#+BEGIN_SRC` or `#+BEGIN_EXAMPLE`, are kept
+    /// as is.
+    ///
+    /// ```rust
+    /// use orgize::{NormalizeOptions, Org};
+    ///
+    /// let org = Org::parse(r#"**   TODO tasks
+    ///
+    ///
+    /// |name|qty|
+    /// |-
+    /// |apple|10|
+    ///
+    ///
+    /// #+BEGIN_SRC python
+    /// def f():
+    ///
+    ///
+    ///     return  1
+    /// #+END_SRC
+    /// "#);
+    ///
+    /// assert_eq!(
+    ///     org.to_org_normalized(),
+    ///     r#"** TODO tasks
+    ///
+    /// | name  | qty |
+    /// |-------+-----|
+    /// | apple | 10  |
+    ///
+    /// #+BEGIN_SRC python
+    /// def f():
+    ///
+    ///
+    ///     return  1
+    /// #+END_SRC
+    /// "#
+    /// );
+    ///
+    /// let options = NormalizeOptions { align_tables: false };
+    /// assert!(org.to_org_normalized_with(&options).contains("|name|qty|\n|-\n|apple|10|\n"));
+    /// ```
+    pub fn to_org_normalized_with(&self, options: &NormalizeOptions) -> String {
+        let mut output = String::new();
+        let mut after_blank_line = false;
+
+        let root = SyntaxNode::new_root(self.green.clone());
+        let mut preorder = root.preorder_with_tokens();
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(element) = event else {
+                continue;
+            };
+
+            match element {
+                NodeOrToken::Node(node) => {
+                    let Some(table) = OrgTable::cast(node).filter(|_| options.align_tables) else {
+                        continue;
+                    };
+                    preorder.skip_subtree();
+
+                    let blank_lines = table
+                        .syntax
+                        .children_with_tokens()
+                        .filter(|e| e.kind() == SyntaxKind::BLANK_LINE)
+                        .map(|e| e.to_string())
+                        .collect::<String>();
+                    let aligned = table.align();
+                    output.push_str(aligned.strip_suffix(&blank_lines).unwrap_or(&aligned));
+                    after_blank_line = false;
+
+                    if !blank_lines.is_empty() {
+                        output.push('\n');
+                        after_blank_line = true;
+                    }
+                }
+                NodeOrToken::Token(token) => match token.kind() {
+                    SyntaxKind::BLANK_LINE => {
+                        if !after_blank_line || in_list(&token) {
+                            output.push_str(token.text().trim_start_matches([' ', '\t']));
+                        }
+                        after_blank_line = true;
+                    }
+                    SyntaxKind::WHITESPACE
+                        if token
+                            .prev_sibling_or_token()
+                            .is_some_and(|e| e.kind() == SyntaxKind::HEADLINE_STARS) =>
+                    {
+                        output.push(' ');
+                        after_blank_line = false;
+                    }
+                    _ if token.text().is_empty() => {}
+                    _ => {
+                        output.push_str(token.text());
+                        after_blank_line = false;
+                    }
+                },
+            }
+        }
+
+        output
+    }
+}
+
+fn in_list(token: &SyntaxToken) -> bool {
+    token
+        .parent_ancestors()
+        .any(|n| n.kind() == SyntaxKind::LIST)
+}
@@ -1,16 +1,51 @@
 use rowan::ast::AstNode;
-use rowan::{GreenNode, TextSize};
+use rowan::{GreenNode, TextRange, TextSize};
+use std::sync::OnceLock;
 
-use crate::ast::Document;
+use crate::ast::{Document, Drawer, Headline, Link, PropertyDrawer};
 use crate::config::ParseConfig;
 use crate::export::{HtmlExport, TraversalContext, Traverser};
-use crate::syntax::{OrgLanguage, SyntaxNode};
-use crate::SyntaxElement;
+use crate::resolve::NamedElement;
+use crate::syntax::{combinator::line_starts_iter, OrgLanguage, SyntaxNode};
+use crate::{SyntaxElement, SyntaxToken};
+
+/// What [`Org::walk_headlines`] does after visiting a headline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalkAction {
+    /// Visit child headlines, then following headlines
+    Continue,
+    /// Skip child headlines, but visit following headlines
+    SkipChildren,
+    /// Stop walking
+    Stop,
+}
+
+/// Element parsers usable with [`Org::parse_element`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementParser {
+    /// A paragraph, up to the first blank line
+    Paragraph,
+    /// A headline, including its section and sub-headlines
+    Headline,
+    /// A plain list
+    List,
+    /// An org table or a table.el table
+    Table,
+    /// A greater or lesser block, like `#+BEGIN_SRC` or `#+BEGIN_QUOTE`
+    Block,
+}
 
 #[derive(Debug)]
 pub struct Org {
     pub(crate) green: GreenNode,
+    /// Config used for parsing, including todo keywords declared in-buffer
     pub(crate) config: ParseConfig,
+    /// Config given by user, without in-buffer settings
+    pub(crate) base_config: ParseConfig,
+    /// Named elements, built on first link resolution
+    pub(crate) names: OnceLock<Vec<NamedElement>>,
+    /// Offsets of line starts, built on first position lookup
+    pub(crate) line_starts: OnceLock<Vec<TextSize>>,
 }
 
 impl Org {
@@ -19,6 +54,152 @@ impl Org {
         ParseConfig::default().parse(input)
     }
 
+    /// Parse input string to Org element tree using given parse config
+    ///
+    /// Shorthand for `config.clone().parse(input)`.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ParseConfig, ast::Headline};
+    ///
+    /// let config = ParseConfig {
+    ///     todo_keywords: (vec!["NEXT".into()], vec!["CANCELLED".into()]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let org = Org::parse_with_config("* NEXT a", &config);
+    /// let hdl = org.first_node::<Headline>().unwrap();
+    /// assert_eq!(hdl.todo_keyword().unwrap(), "NEXT");
+    /// assert_eq!(org.config().todo_keywords.0, ["NEXT"]);
+    ///
+    /// let org = Org::parse("* NEXT a");
+    /// assert!(org.first_node::<Headline>().unwrap().todo_keyword().is_none());
+    /// ```
+    pub fn parse_with_config(input: impl AsRef<str>, config: &ParseConfig) -> Org {
+        config.clone().parse(input)
+    }
+
+    /// Parse input string using default parse config, and collect positions
+    /// where the parser fell back to plain text
+    ///
+    /// Parsing never fails, so malformed constructs like a `[[` without
+    /// closing `]]`, or a `#+BEGIN_SRC` without `#+END_SRC`, silently become
+    /// text. Each returned range points at such a construct, along with a
+    /// short description.
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// let (org, points) = Org::parse_with_recovery("* see [[foo\n#+BEGIN_SRC rust\nlet a;\n");
+    /// let text = org.to_org();
+    /// assert_eq!(points.len(), 2);
+    /// assert_eq!(&text[points[0].0], "[[");
+    /// assert_eq!(points[0].1, "unclosed link");
+    /// assert_eq!(&text[points[1].0], "#+BEGIN_SRC rust");
+    /// assert_eq!(points[1].1, "unterminated block");
+    ///
+    /// let (_, points) = Org::parse_with_recovery("* see [[foo]]\n#+BEGIN_SRC rust\n#+END_SRC\n");
+    /// assert!(points.is_empty());
+    /// ```
+    pub fn parse_with_recovery(input: impl AsRef<str>) -> (Org, Vec<(TextRange, &'static str)>) {
+        let org = Org::parse(input);
+        let points = crate::recovery::recovery_points(&SyntaxNode::new_root(org.green.clone()));
+        (org, points)
+    }
+
+    /// Parses a single element with current config, without building a
+    /// whole document
+    ///
+    /// Returns `None` if `input` isn't exactly one element of given type,
+    /// i.e. it doesn't start with the element, or anything is left after it.
+    ///
+    /// ```rust
+    /// use orgize::{ElementParser, Org, SyntaxKind};
+    ///
+    /// let org = Org::parse("");
+    ///
+    /// let node = org.parse_element("*bold* text", ElementParser::Paragraph).unwrap();
+    /// assert_eq!(node.kind(), SyntaxKind::PARAGRAPH);
+    /// assert_eq!(node.first_child().unwrap().kind(), SyntaxKind::BOLD);
+    ///
+    /// let node = org.parse_element("- a\n- b\n\n\n", ElementParser::List).unwrap();
+    /// assert_eq!(node.kind(), SyntaxKind::LIST);
+    /// assert_eq!(node.to_string(), "- a\n- b\n\n\n");
+    ///
+    /// assert!(org.parse_element("text", ElementParser::Headline).is_none());
+    /// assert!(org.parse_element("- a\n- b\n\n\ntext", ElementParser::List).is_none());
+    /// assert!(org.parse_element("#+BEGIN_QUOTE\na\n#+END_QUOTE\ngarbage", ElementParser::Block).is_none());
+    /// ```
+    pub fn parse_element(&self, input: &str, parser: ElementParser) -> Option<SyntaxNode> {
+        use crate::syntax::{block, headline, input::Input, list, paragraph, table};
+
+        if input.is_empty() {
+            return None;
+        }
+
+        let input = Input {
+            s: input,
+            c: &self.config,
+        };
+
+        let (rest, element) = match parser {
+            ElementParser::Paragraph => paragraph::paragraph_node(input),
+            ElementParser::Headline => headline::headline_node(input),
+            ElementParser::List => list::list_node(input),
+            ElementParser::Table => {
+                table::org_table_node(input).or_else(|_| table::table_el_node(input))
+            }
+            ElementParser::Block => block::block_node(input),
+        }
+        .ok()?;
+
+        if !rest.is_empty() {
+            return None;
+        }
+
+        Some(SyntaxNode::new_root(element.into_node()?))
+    }
+
+    /// Returns offsets where each line starts, computed once per document
+    ///
+    /// The first line always starts at zero. `\n`, `\r\n` and `\r` are
+    /// all treated as line endings.
+    ///
+    /// ```rust
+    /// use orgize::{Org, TextSize};
+    ///
+    /// let org = Org::parse("* a\r\ntext\n\nb");
+    /// assert_eq!(
+    ///     org.line_starts(),
+    ///     [0, 5, 10, 11].map(TextSize::from)
+    /// );
+    /// ```
+    pub fn line_starts(&self) -> &[TextSize] {
+        self.line_starts.get_or_init(|| {
+            line_starts_iter(&self.green.to_string())
+                .map(|i| TextSize::from(i as u32))
+                .collect()
+        })
+    }
+
+    /// Returns 0-based line and column of given offset
+    ///
+    /// Column is counted in bytes from the start of the line. See also
+    /// [`Spanned::line_column`](crate::ast::Spanned::line_column).
+    ///
+    /// ```rust
+    /// use orgize::{Org, TextSize};
+    ///
+    /// let org = Org::parse("* a\r\ntext\n\nb");
+    /// assert_eq!(org.line_column(TextSize::from(0)), (0, 0));
+    /// assert_eq!(org.line_column(TextSize::from(7)), (1, 2));
+    /// assert_eq!(org.line_column(TextSize::from(11)), (3, 0));
+    /// ```
+    pub fn line_column(&self, offset: TextSize) -> (u32, u32) {
+        let line_starts = self.line_starts();
+        let line = line_starts.partition_point(|&start| start <= offset) - 1;
+        (line as u32, u32::from(offset - line_starts[line]))
+    }
+
     pub fn green(&self) -> &GreenNode {
         &self.green
     }
@@ -67,6 +248,182 @@ impl Org {
         find(SyntaxNode::new_root(self.green.clone()))
     }
 
+    /// Visits headlines in document order, letting the callback decide
+    /// whether to descend into each subtree
+    ///
+    /// Headlines are visited lazily, so skipped subtrees aren't traversed.
+    ///
+    /// ```rust
+    /// use orgize::{Org, WalkAction};
+    ///
+    /// let org = Org::parse("* a\n** b\n* old :ARCHIVE:\n** c\n* d\n** e\n* f");
+    ///
+    /// let mut titles = vec![];
+    /// org.walk_headlines(|hdl| {
+    ///     let title = hdl.title_raw().trim().to_string();
+    ///     titles.push(title.clone());
+    ///     if hdl.is_archived() {
+    ///         WalkAction::SkipChildren
+    ///     } else if title == "e" {
+    ///         WalkAction::Stop
+    ///     } else {
+    ///         WalkAction::Continue
+    ///     }
+    /// });
+    /// assert_eq!(titles, vec!["a", "b", "old", "d", "e"]);
+    /// ```
+    pub fn walk_headlines(&self, mut f: impl FnMut(&Headline) -> WalkAction) {
+        fn walk(
+            headlines: impl Iterator<Item = Headline>,
+            f: &mut impl FnMut(&Headline) -> WalkAction,
+        ) -> bool {
+            for headline in headlines {
+                match f(&headline) {
+                    WalkAction::Continue => {
+                        if walk(headline.headlines(), f) {
+                            return true;
+                        }
+                    }
+                    WalkAction::SkipChildren => {}
+                    WalkAction::Stop => return true,
+                }
+            }
+            false
+        }
+
+        walk(self.document().headlines(), &mut f);
+    }
+
+    /// Returns an iterator of all headlines in document order, i.e. depth
+    /// first
+    ///
+    /// Direct sub-headlines of a headline are returned by
+    /// [`Headline::headlines`], and its parent by
+    /// [`Headline::parent_headline`].
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// let org = Org::parse("* a\n*** b\n** c\n* d\n** e");
+    /// let titles = org.headlines().map(|h| h.title_raw()).collect::<Vec<_>>();
+    /// assert_eq!(titles, vec!["a", "b", "c", "d", "e"]);
+    ///
+    /// let b = org.headlines().nth(1).unwrap();
+    /// assert_eq!(b.level(), 3);
+    /// assert_eq!(b.parent_headline().unwrap().title_raw(), "a");
+    /// ```
+    pub fn headlines(&self) -> impl Iterator<Item = Headline> {
+        SyntaxNode::new_root(self.green.clone())
+            .descendants()
+            .filter_map(Headline::cast)
+    }
+
+    /// Returns an iterator of all drawers in document order
+    ///
+    /// Property drawers are not included, see [`Org::property_drawers`].
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// let org = Org::parse(r#"
+    /// :NOTE:
+    /// a
+    /// :END:
+    /// * headline
+    /// :PROPERTIES:
+    /// :ID: 1
+    /// :END:
+    /// :LOGBOOK:
+    /// CLOCK: [2024-01-01 Mon 10:00]--[2024-01-01 Mon 11:00] =>  1:00
+    /// :END:
+    /// - item
+    ///   :DETAILS:
+    ///   b
+    ///   :END:
+    /// "#);
+    /// let names = org.drawers().map(|d| d.name().to_string()).collect::<Vec<_>>();
+    /// assert_eq!(names, vec!["NOTE", "LOGBOOK", "DETAILS"]);
+    /// ```
+    pub fn drawers(&self) -> impl Iterator<Item = Drawer> {
+        SyntaxNode::new_root(self.green.clone())
+            .descendants()
+            .filter_map(Drawer::cast)
+    }
+
+    /// Returns an iterator of all links in document order, each paired with
+    /// the headline it belongs to
+    ///
+    /// Links before the first headline are paired with `None`. Links in a
+    /// headline's title belong to that headline. Links nested in lists,
+    /// tables and footnote definitions are included as well.
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// let org = Org::parse(r#"[[https://orgmode.org]]
+    /// * a [[#b][b]]
+    /// - [[file:a.org]]
+    /// ** c
+    /// | [[https://example.com]] |
+    ///
+    /// [fn:1] [[https://rust-lang.org]]
+    /// "#);
+    /// let links = org
+    ///     .links()
+    ///     .map(|(link, hdl)| (link.path().to_string(), hdl.map(|h| h.title_raw())))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     links,
+    ///     vec![
+    ///         ("https://orgmode.org".into(), None),
+    ///         ("#b".into(), Some("a [[#b][b]]".into())),
+    ///         ("file:a.org".into(), Some("a [[#b][b]]".into())),
+    ///         ("https://example.com".into(), Some("c".into())),
+    ///         ("https://rust-lang.org".into(), Some("c".into())),
+    ///     ]
+    /// );
+    /// ```
+    pub fn links(&self) -> impl Iterator<Item = (Link, Option<Headline>)> {
+        SyntaxNode::new_root(self.green.clone())
+            .descendants()
+            .filter_map(Link::cast)
+            .map(|link| {
+                let headline = link.syntax().ancestors().find_map(Headline::cast);
+                (link, headline)
+            })
+    }
+
+    /// Returns an iterator of all property drawers in document order,
+    /// including the top-level one
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// let org = Org::parse(r#":PROPERTIES:
+    /// :ID: doc
+    /// :END:
+    /// * a
+    /// :PROPERTIES:
+    /// :ID: a
+    /// :END:
+    /// ** b
+    /// :PROPERTIES:
+    /// :ID: b
+    /// :END:
+    /// "#);
+    /// let ids = org
+    ///     .property_drawers()
+    ///     .filter_map(|d| d.get("ID"))
+    ///     .map(|id| id.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(ids, vec!["doc", "a", "b"]);
+    /// ```
+    pub fn property_drawers(&self) -> impl Iterator<Item = PropertyDrawer> {
+        SyntaxNode::new_root(self.green.clone())
+            .descendants()
+            .filter_map(PropertyDrawer::cast)
+    }
+
     /// Returns node in given offset
     ///
     /// ```rust
@@ -103,4 +460,68 @@ impl Org {
         }
         find(SyntaxNode::new_root(self.green.clone()), offset)
     }
+
+    /// Returns the innermost token at given offset
+    ///
+    /// If the offset sits between two tokens, the token starting at the
+    /// offset is returned. Returns `None` if the offset is out of range.
+    ///
+    /// ```rust
+    /// use orgize::{Org, SyntaxKind};
+    ///
+    /// let org = Org::parse("* TODO a *b*");
+    /// let token = org.token_at_offset(3).unwrap();
+    /// assert_eq!(token.kind(), SyntaxKind::HEADLINE_KEYWORD_TODO);
+    /// assert_eq!(token.text(), "TODO");
+    /// let token = org.token_at_offset(10).unwrap();
+    /// assert_eq!(token.kind(), SyntaxKind::TEXT);
+    /// assert_eq!(token.parent().unwrap().kind(), SyntaxKind::BOLD);
+    ///
+    /// assert!(org.token_at_offset(100).is_none());
+    /// ```
+    pub fn token_at_offset(&self, offset: impl Into<TextSize>) -> Option<SyntaxToken> {
+        let offset = offset.into();
+        let root = SyntaxNode::new_root(self.green.clone());
+        if !root.text_range().contains_inclusive(offset) {
+            return None;
+        }
+        root.token_at_offset(offset).right_biased()
+    }
+
+    /// Returns the nearest node of type `N` containing given offset
+    ///
+    /// Unlike [`Org::node_at_offset`], which returns the outermost node,
+    /// this method walks up from the innermost token, so nested nodes of
+    /// the same type resolve to the deepest one.
+    ///
+    /// If the offset sits between two tokens, the token starting at the
+    /// offset is tried first, then the token ending at it.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ast::{Headline, Link}};
+    ///
+    /// let org = Org::parse("* a\n** b [[c]]\n");
+    /// let hdl = org.element_at_offset::<Headline>(10).unwrap();
+    /// assert_eq!(hdl.title_raw(), "b [[c]]");
+    /// let hdl = org.node_at_offset::<Headline>(10).unwrap();
+    /// assert_eq!(hdl.title_raw(), "a");
+    /// let link = org.element_at_offset::<Link>(10).unwrap();
+    /// assert_eq!(link.path(), "c");
+    ///
+    /// // right after the link
+    /// assert!(org.element_at_offset::<Link>(14).is_some());
+    /// assert!(org.element_at_offset::<Link>(4).is_none());
+    /// ```
+    pub fn element_at_offset<N: AstNode<Language = OrgLanguage>>(
+        &self,
+        offset: impl Into<TextSize>,
+    ) -> Option<N> {
+        let offset = offset.into();
+        let root = SyntaxNode::new_root(self.green.clone());
+        if !root.text_range().contains_inclusive(offset) {
+            return None;
+        }
+        root.token_at_offset(offset)
+            .find_map(|token| token.parent_ancestors().find_map(N::cast))
+    }
 }
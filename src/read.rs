@@ -0,0 +1,129 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::str::Utf8Error;
+
+use crate::{Org, ParseConfig};
+
+/// UTF-8 encoded byte order mark
+const BOM: &str = "\u{FEFF}";
+
+/// Error returned by [`Org::from_reader`] and [`ParseConfig::parse_reader`]
+///
+/// Parsing itself never fails, so errors only come from reading the input.
+#[derive(Debug)]
+pub enum ReadError {
+    /// Reading from the underlying reader failed
+    Io(io::Error),
+    /// Input is not valid UTF-8
+    ///
+    /// [`Utf8Error::valid_up_to`] is the offset in the raw bytes, including
+    /// the byte order mark if there's one.
+    Utf8(Utf8Error),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "failed to read input: {err}"),
+            ReadError::Utf8(err) => write!(f, "input is not valid UTF-8: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(err) => Some(err),
+            ReadError::Utf8(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+impl ParseConfig {
+    /// Reads the whole stream and parses it with current config
+    ///
+    /// `size_hint` is the expected length of input in bytes, like the file
+    /// size, and is only used to preallocate the buffer. Pass `0` if it's
+    /// unknown.
+    ///
+    /// A leading byte order mark is skipped, so offsets in the syntax tree
+    /// are 3 bytes less than offsets in the raw bytes if there's one. Use
+    /// [`Org::from_reader_with_bom`] to know whether it's the case.
+    ///
+    /// ```rust
+    /// use orgize::ParseConfig;
+    ///
+    /// let config = ParseConfig { todo_keywords: (vec!["NEXT".into()], vec![]), ..Default::default() };
+    /// let input = "* NEXT a\n";
+    /// let org = config.parse_reader(input.as_bytes(), input.len()).unwrap();
+    /// assert_eq!(org.to_org(), "* NEXT a\n");
+    /// ```
+    pub fn parse_reader(self, reader: impl Read, size_hint: usize) -> Result<Org, ReadError> {
+        Ok(self.parse_reader_with_bom(reader, size_hint)?.0)
+    }
+
+    fn parse_reader_with_bom(
+        self,
+        mut reader: impl Read,
+        size_hint: usize,
+    ) -> Result<(Org, bool), ReadError> {
+        let mut bytes = Vec::with_capacity(size_hint);
+        reader.read_to_end(&mut bytes)?;
+        let input = std::str::from_utf8(&bytes).map_err(ReadError::Utf8)?;
+        match input.strip_prefix(BOM) {
+            Some(input) => Ok((self.parse(input), true)),
+            None => Ok((self.parse(input), false)),
+        }
+    }
+}
+
+impl Org {
+    /// Reads the whole stream and parses it using default parse config
+    ///
+    /// A leading byte order mark is skipped. See
+    /// [`ParseConfig::parse_reader`] for details.
+    ///
+    /// ```rust
+    /// use orgize::{Org, ReadError};
+    /// use std::io::Cursor;
+    ///
+    /// let input = "#+TITLE: doc\n* TODO a\n** b :tag:\n- [ ] item\n";
+    /// let org = Org::from_reader(Cursor::new(input)).unwrap();
+    /// assert_eq!(org.green(), Org::parse(input).green());
+    ///
+    /// let org = Org::from_reader("\u{FEFF}* a\n".as_bytes()).unwrap();
+    /// assert_eq!(org.to_org(), "* a\n");
+    ///
+    /// let err = Org::from_reader(&b"* a\n\xff"[..]).unwrap_err();
+    /// assert!(matches!(err, ReadError::Utf8(e) if e.valid_up_to() == 4));
+    /// ```
+    pub fn from_reader(reader: impl Read) -> Result<Org, ReadError> {
+        ParseConfig::default().parse_reader(reader, 0)
+    }
+
+    /// Like [`Org::from_reader`], but also returns whether the stream starts
+    /// with a byte order mark
+    ///
+    /// If it does, add 3 to offsets in the syntax tree to get offsets in
+    /// the raw bytes.
+    ///
+    /// ```rust
+    /// use orgize::Org;
+    ///
+    /// let (org, bom) = Org::from_reader_with_bom("\u{FEFF}* a".as_bytes()).unwrap();
+    /// assert!(bom);
+    /// assert_eq!(org.to_org(), "* a");
+    ///
+    /// let (_, bom) = Org::from_reader_with_bom("* a".as_bytes()).unwrap();
+    /// assert!(!bom);
+    /// ```
+    pub fn from_reader_with_bom(reader: impl Read) -> Result<(Org, bool), ReadError> {
+        ParseConfig::default().parse_reader_with_bom(reader, 0)
+    }
+}
@@ -0,0 +1,117 @@
+use rowan::{TextRange, TextSize};
+
+use crate::syntax::{SyntaxKind, SyntaxNode};
+
+/// Openers of objects, which are only left in text if the object failed to parse
+const OBJECT_OPENERS: &[(&str, &str)] = &[
+    ("[[", "unclosed link"),
+    ("[fn:", "unclosed footnote reference"),
+    ("{{{", "unclosed macro"),
+    ("@@", "unclosed export snippet"),
+];
+
+/// Returns positions where the parser fell back to plain text
+///
+/// The tree is lossless, so malformed constructs end up as text. This
+/// function scans text in paragraphs and headline titles for openers of
+/// objects, and lines in paragraphs that look like the beginning of a
+/// greater element.
+pub(crate) fn recovery_points(root: &SyntaxNode) -> Vec<(TextRange, &'static str)> {
+    let mut points = vec![];
+
+    for node in root.descendants() {
+        match node.kind() {
+            SyntaxKind::PARAGRAPH => {
+                element_lines(&node, &mut points);
+                object_text(&node, &mut points);
+            }
+            SyntaxKind::HEADLINE_TITLE
+            | SyntaxKind::ORG_TABLE_CELL
+            | SyntaxKind::LIST_ITEM_TAG
+            | SyntaxKind::BOLD
+            | SyntaxKind::ITALIC
+            | SyntaxKind::UNDERLINE
+            | SyntaxKind::STRIKE => object_text(&node, &mut points),
+            _ => {}
+        }
+    }
+
+    points.sort_by_key(|(range, _)| range.start());
+    points
+}
+
+fn object_text(node: &SyntaxNode, points: &mut Vec<(TextRange, &'static str)>) {
+    for token in node
+        .children_with_tokens()
+        .filter_map(|e| e.into_token())
+        .filter(|t| t.kind() == SyntaxKind::TEXT)
+    {
+        let text = token.text();
+        let start = token.text_range().start();
+        for (opener, message) in OBJECT_OPENERS {
+            for (idx, _) in text.match_indices(opener) {
+                points.push((range(start, idx, opener.len()), message));
+            }
+        }
+    }
+}
+
+fn element_lines(node: &SyntaxNode, points: &mut Vec<(TextRange, &'static str)>) {
+    let Some(first) = node
+        .children_with_tokens()
+        .find(|e| e.kind() != SyntaxKind::AFFILIATED_KEYWORD && e.kind() != SyntaxKind::BLANK_LINE)
+    else {
+        return;
+    };
+    let start = first.text_range().start();
+    let text = node
+        .text()
+        .slice(start - node.text_range().start()..)
+        .to_string();
+
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        let indent = content.len() - content.trim_start().len();
+        let trimmed = &content[indent..];
+
+        let message = if starts_with_ignore_case(trimmed, "#+BEGIN_") {
+            Some("unterminated block")
+        } else if starts_with_ignore_case(trimmed, "#+BEGIN:") {
+            Some("unterminated dynamic block")
+        } else if trimmed.starts_with("\\begin{") {
+            Some("unterminated LaTeX environment")
+        } else if is_drawer_begin(trimmed) {
+            Some("unterminated drawer")
+        } else {
+            None
+        };
+
+        if let Some(message) = message {
+            points.push((range(start, offset + indent, trimmed.len()), message));
+        }
+
+        offset += line.len();
+    }
+}
+
+fn is_drawer_begin(s: &str) -> bool {
+    let s = s.trim_end();
+    s.len() > 2
+        && s.starts_with(':')
+        && s.ends_with(':')
+        && !s.eq_ignore_ascii_case(":END:")
+        && s[1..s.len() - 1]
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+}
+
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.get(..prefix.len())
+        .is_some_and(|s| s.eq_ignore_ascii_case(prefix))
+}
+
+fn range(start: TextSize, offset: usize, len: usize) -> TextRange {
+    let start = start + TextSize::from(offset as u32);
+    TextRange::at(start, TextSize::from(len as u32))
+}
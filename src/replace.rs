@@ -64,7 +64,7 @@ impl ReplaceWithShape {
                 continue;
             }
 
-            if !matches!(text[start..].as_bytes().get(level), Some(b' ')) {
+            if !matches!(text.as_bytes()[start..].get(level), Some(b' ')) {
                 continue;
             }
 
@@ -114,6 +114,8 @@ impl Org {
     /// ```
     pub fn replace_range(&mut self, range: TextRange, replace_with: impl AsRef<str>) {
         let replace_with = replace_with.as_ref();
+        self.names.take();
+        self.line_starts.take();
         match (
             RangeShape::new(self.document().syntax, range),
             ReplaceWithShape::new(replace_with),
@@ -133,7 +135,7 @@ impl Org {
             ) if level <= new_level
             // non-last headline must ends with a newline
                 && (headline.end() == self.document().end()
-                    || replace_with.ends_with(&['\n', '\r'])) =>
+                    || replace_with.ends_with(['\n', '\r'])) =>
             {
                 self.replace_headline(headline, range, replace_with)
             }
@@ -147,6 +149,9 @@ impl Org {
 
             _ => self.full_parse(range, replace_with),
         }
+
+        // in-buffer todo keywords may have been added or removed
+        self.sync_buffer_keywords();
     }
 
     fn full_parse(&mut self, range: TextRange, replace_with: &str) {
@@ -324,3 +329,52 @@ fn replace() {
     t!("* abc \n|* edf\n|* gh", "* hg\n");
     t!("* abc \n* edf\n|* gh|", "* hg");
 }
+
+#[test]
+fn replace_todo_keywords() {
+    use crate::ast::{Headline, TodoType};
+
+    let keyword = |org: &Org| {
+        org.first_node::<Headline>()
+            .unwrap()
+            .todo_keyword()
+            .map(|k| k.to_string())
+    };
+
+    // removing the #+TODO line
+    let mut org = Org::parse("#+TODO: A | B\n* A x");
+    assert_eq!(keyword(&org).unwrap(), "A");
+    org.replace_range(TextRange::new(0.into(), 14.into()), "");
+    assert_eq!(org.to_org(), "* A x");
+    assert!(keyword(&org).is_none());
+    assert_eq!(org.config().todo_keywords.0, ["TODO"]);
+    assert_eq!(keyword(&org), keyword(&Org::parse(org.to_org())));
+
+    // adding the #+TODO line
+    let mut org = Org::parse("* A x");
+    assert!(keyword(&org).is_none());
+    org.replace_range(TextRange::new(0.into(), 0.into()), "#+TODO: A | B\n");
+    assert_eq!(keyword(&org).unwrap(), "A");
+    assert_eq!(keyword(&org), keyword(&Org::parse(org.to_org())));
+
+    // editing the #+TODO line
+    org.replace_range(TextRange::new(8.into(), 9.into()), "C");
+    assert_eq!(org.to_org(), "#+TODO: C | B\n* A x");
+    assert!(keyword(&org).is_none());
+
+    // editing a headline keeps in-buffer keywords
+    let mut org = Org::parse("#+TODO: A | B\n* x");
+    org.replace_range(TextRange::new(16.into(), 17.into()), "B x");
+    let hdl = org.first_node::<Headline>().unwrap();
+    assert_eq!(hdl.todo_type(), Some(TodoType::Done));
+
+    // user config is kept
+    let config = crate::ParseConfig {
+        todo_keywords: (vec!["NEXT".into()], vec![]),
+        ..Default::default()
+    };
+    let mut org = config.parse("#+TODO: A | B\n* NEXT x");
+    org.replace_range(TextRange::new(0.into(), 14.into()), "");
+    assert_eq!(keyword(&org).unwrap(), "NEXT");
+    assert_eq!(org.config().todo_keywords.0, ["NEXT"]);
+}
@@ -0,0 +1,225 @@
+use rowan::{ast::AstNode, NodeOrToken, TextRange};
+
+use crate::{
+    ast::{push_plain_text, AffiliatedKeyword, Headline, Link},
+    syntax::{link::has_link_type, SyntaxKind},
+    Org, SyntaxNode,
+};
+
+/// Controls how [`Org::resolve_link_with`] matches fuzzy links against
+/// headline titles
+///
+/// Both the link path and the title are normalized before comparing:
+///
+/// 1. statistics cookies in the title, like `[1/3]` or `[50%]`, are removed,
+/// 2. markup is removed from the title if [`plain_title`](Self::plain_title) is set,
+/// 3. runs of spaces, tabs and newlines are collapsed into a single space,
+///    and leading and trailing whitespace is removed,
+/// 4. both sides are lowercased if [`ignore_case`](Self::ignore_case) is set.
+///
+/// TODO keywords, priorities and tags are never part of the title.
+#[derive(Clone, Debug)]
+pub struct ResolveOptions {
+    /// Compare titles case-insensitively
+    ///
+    /// Defaults to `false`.
+    pub ignore_case: bool,
+
+    /// Match against titles with markup removed, so `[[My Heading]]` matches
+    /// `* My /Heading/`. Otherwise titles are matched as written.
+    ///
+    /// Links are replaced by their description, or by their path if they
+    /// don't have one, same as [`Paragraph::text`](crate::ast::Paragraph::text).
+    ///
+    /// Defaults to `true`.
+    pub plain_title: bool,
+}
+
+impl Default for ResolveOptions {
+    fn default() -> Self {
+        ResolveOptions {
+            ignore_case: false,
+            plain_title: true,
+        }
+    }
+}
+
+/// Element a link points to, returned by [`Org::resolve_link`]
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinkTarget {
+    Headline(Headline),
+    /// Element with a `#+NAME` keyword, like a table or a source block
+    Element(SyntaxNode),
+}
+
+/// Element with a `#+NAME` keyword
+#[derive(Debug)]
+pub(crate) struct NamedElement {
+    name: String,
+    range: TextRange,
+    kind: SyntaxKind,
+}
+
+/// Returns the value of `#+NAME` keyword of given element
+pub(crate) fn element_name(node: &SyntaxNode) -> Option<String> {
+    node.children()
+        .filter_map(AffiliatedKeyword::cast)
+        .find(|k| k.key().eq_ignore_ascii_case("NAME"))
+        .and_then(|k| k.value())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+impl ResolveOptions {
+    fn normalize(&self, s: &str) -> String {
+        let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+        if self.ignore_case {
+            s.to_lowercase()
+        } else {
+            s
+        }
+    }
+
+    fn title(&self, headline: &Headline) -> String {
+        let mut title = String::new();
+        for element in headline.title() {
+            if element.kind() == SyntaxKind::COOKIE {
+                continue;
+            }
+            if self.plain_title {
+                push_plain_text(&mut title, element);
+            } else {
+                title.push_str(&element.to_string());
+            }
+        }
+        self.normalize(&title)
+    }
+}
+
+impl Org {
+    /// Returns named elements in document order, collected once per document
+    fn named_elements(&self) -> &[NamedElement] {
+        self.names.get_or_init(|| {
+            SyntaxNode::new_root(self.green.clone())
+                .descendants()
+                .filter_map(|node| {
+                    Some(NamedElement {
+                        name: element_name(&node)?,
+                        range: node.text_range(),
+                        kind: node.kind(),
+                    })
+                })
+                .collect()
+        })
+    }
+
+    /// Resolves a fuzzy link to the element it points to, using default
+    /// options
+    ///
+    /// Equivalent to `resolve_link_with(link, &ResolveOptions::default())`.
+    pub fn resolve_link(&self, link: &Link) -> Option<LinkTarget> {
+        self.resolve_link_with(link, &ResolveOptions::default())
+    }
+
+    /// Resolves a fuzzy link to the element it points to
+    ///
+    /// A link is fuzzy if it has no link type, like `[[My Heading]]` or
+    /// `[[*My Heading]]`. Unless it starts with `*`, it's first resolved to
+    /// the first element whose `#+NAME` matches, see [`Org::resolve_name`].
+    /// Otherwise it's resolved to the first headline whose title matches,
+    /// see [`ResolveOptions`] on how titles are compared. Returns `None` for
+    /// other links, or if nothing matches.
+    ///
+    /// ```rust
+    /// use orgize::{ast::Link, LinkTarget, Org, ResolveOptions};
+    ///
+    /// let org = Org::parse(r#"* TODO [#A] My  Heading :tag:
+    /// * Tasks [1/2]
+    /// * The /best/ [[https://orgmode.org][Org]] way
+    ///
+    /// [[My Heading]] [[*Tasks]] [[The best Org way]] [[my heading]]
+    /// "#);
+    /// let links = org.links().map(|(link, _)| link).collect::<Vec<_>>();
+    ///
+    /// let resolve = |link: &Link, options: &ResolveOptions| match org.resolve_link_with(link, options) {
+    ///     Some(LinkTarget::Headline(hdl)) => Some(hdl.title_raw()),
+    ///     _ => None,
+    /// };
+    ///
+    /// let options = ResolveOptions::default();
+    /// // the link inside the third headline
+    /// assert_eq!(resolve(&links[0], &options), None);
+    /// assert_eq!(resolve(&links[1], &options).unwrap(), "My  Heading ");
+    /// assert_eq!(resolve(&links[2], &options).unwrap(), "Tasks [1/2]");
+    /// assert!(resolve(&links[3], &options).unwrap().starts_with("The /best/"));
+    /// assert_eq!(resolve(&links[4], &options), None);
+    ///
+    /// let options = ResolveOptions { ignore_case: true, ..Default::default() };
+    /// assert_eq!(resolve(&links[4], &options).unwrap(), "My  Heading ");
+    ///
+    /// let org = Org::parse("* The /best/ way\n[[The /best/ way]]");
+    /// let link = org.first_node::<Link>().unwrap();
+    /// assert!(org.resolve_link(&link).is_none());
+    /// let options = ResolveOptions { plain_title: false, ..Default::default() };
+    /// assert!(org.resolve_link_with(&link, &options).is_some());
+    /// ```
+    pub fn resolve_link_with(&self, link: &Link, options: &ResolveOptions) -> Option<LinkTarget> {
+        let path = link.path();
+
+        if path.is_empty() || path.starts_with(['#', '(', '/', '.', '~']) || has_link_type(&path) {
+            return None;
+        }
+
+        if let Some(path) = path.strip_prefix('*') {
+            return self.resolve_headline(&options.normalize(path), options);
+        }
+
+        self.resolve_name(&path)
+            .map(LinkTarget::Element)
+            .or_else(|| self.resolve_headline(&options.normalize(&path), options))
+    }
+
+    /// Returns the first element whose `#+NAME` equals `name`
+    ///
+    /// Names are compared exactly, after removing surrounding whitespace.
+    ///
+    /// ```rust
+    /// use orgize::{ast::{Link, SourceBlock}, rowan::ast::AstNode, LinkTarget, Org};
+    ///
+    /// let org = Org::parse(r#"* results
+    /// #+NAME: results
+    /// #+BEGIN_SRC rust
+    /// fn main() {}
+    /// #+END_SRC
+    ///
+    /// [[results]] [[*results]]
+    /// "#);
+    /// let block = org.resolve_name("results").unwrap();
+    /// assert!(SourceBlock::can_cast(block.kind()));
+    /// assert!(org.resolve_name("Results").is_none());
+    ///
+    /// let links = org.links().map(|(link, _)| link).collect::<Vec<_>>();
+    /// assert_eq!(org.resolve_link(&links[0]), Some(LinkTarget::Element(block)));
+    /// assert!(matches!(org.resolve_link(&links[1]), Some(LinkTarget::Headline(_))));
+    /// ```
+    pub fn resolve_name(&self, name: &str) -> Option<SyntaxNode> {
+        let name = name.trim();
+        let element = self.named_elements().iter().find(|e| e.name == name)?;
+        let root = SyntaxNode::new_root(self.green.clone());
+        match root.covering_element(element.range) {
+            NodeOrToken::Node(node) => node,
+            NodeOrToken::Token(token) => token.parent()?,
+        }
+        .ancestors()
+        .find(|node| node.kind() == element.kind && node.text_range() == element.range)
+    }
+
+    fn resolve_headline(&self, path: &str, options: &ResolveOptions) -> Option<LinkTarget> {
+        SyntaxNode::new_root(self.green.clone())
+            .descendants()
+            .filter_map(Headline::cast)
+            .find(|headline| options.title(headline) == *path)
+            .map(LinkTarget::Headline)
+    }
+}
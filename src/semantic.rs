@@ -0,0 +1,222 @@
+use rowan::{TextRange, TextSize, WalkEvent};
+
+use crate::{syntax::SyntaxKind, Org, SyntaxElement, SyntaxNode};
+
+/// Type of a [`SemanticToken`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SemanticTokenType {
+    /// Stars of a headline
+    HeadlineStars,
+    /// Todo keyword of a headline, like `TODO`
+    TodoKeyword,
+    /// Done keyword of a headline, like `DONE`
+    DoneKeyword,
+    /// Priority cookie of a headline, like `[#A]`
+    Priority,
+    /// Tags of a headline, including colons
+    Tags,
+    /// `SCHEDULED:`, `DEADLINE:` or `CLOSED:` in planning lines
+    PlanningKeyword,
+    Timestamp,
+    Link,
+    /// Text and markers of bold markup, except nested objects
+    Bold,
+    /// Text and markers of italic markup, except nested objects
+    Italic,
+    /// Text and markers of underline markup, except nested objects
+    Underline,
+    /// Text and markers of strike-through markup, except nested objects
+    Strike,
+    Code,
+    Verbatim,
+    Macro,
+    Entity,
+    FootnoteReference,
+    /// Target or radio target
+    Target,
+    /// Statistics cookie, like `[1/3]`
+    Cookie,
+    LatexFragment,
+    /// Export snippet, like `@@html:<br>@@`
+    Snippet,
+    /// Inline source block or inline babel call
+    InlineSource,
+    Comment,
+    /// Keyword, affiliated keyword or babel call line
+    Keyword,
+    /// Begin or end line of a block
+    BlockDelimiter,
+    /// Begin or end line of a drawer
+    DrawerDelimiter,
+    /// Bullet of a list item
+    ListBullet,
+    /// Checkbox of a list item, like `[X]`
+    Checkbox,
+}
+
+/// A highlighted span, returned by [`Org::semantic_tokens`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemanticToken {
+    pub range: TextRange,
+    pub ty: SemanticTokenType,
+}
+
+impl Org {
+    /// Returns highlighted spans of this document, for semantic syntax
+    /// highlighting
+    ///
+    /// Spans are in document order and don't overlap, so they can be
+    /// served as LSP semantic tokens. Objects nested in emphasis markup get
+    /// their own spans, and the markup only covers what's left. Spans never
+    /// cross line endings: an element spanning several lines, like a
+    /// comment, gets a span per line. Trailing whitespace and line endings
+    /// are not included.
+    ///
+    /// ```rust
+    /// use orgize::{Org, SemanticTokenType};
+    ///
+    /// let org = Org::parse("* TODO [#A] a *b [[l]]* :x:\n#+TITLE: t");
+    /// let text = org.to_org();
+    /// let tokens = org
+    ///     .semantic_tokens()
+    ///     .into_iter()
+    ///     .map(|t| (t.ty, &text[t.range]))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     tokens,
+    ///     vec![
+    ///         (SemanticTokenType::HeadlineStars, "*"),
+    ///         (SemanticTokenType::TodoKeyword, "TODO"),
+    ///         (SemanticTokenType::Priority, "[#A]"),
+    ///         (SemanticTokenType::Bold, "*"),
+    ///         (SemanticTokenType::Bold, "b"),
+    ///         (SemanticTokenType::Link, "[[l]]"),
+    ///         (SemanticTokenType::Bold, "*"),
+    ///         (SemanticTokenType::Tags, ":x:"),
+    ///         (SemanticTokenType::Keyword, "#+TITLE: t"),
+    ///     ]
+    /// );
+    ///
+    /// let org = Org::parse("# a\r\n#\n  # b\n");
+    /// let text = org.to_org();
+    /// let tokens = org
+    ///     .semantic_tokens()
+    ///     .into_iter()
+    ///     .map(|t| (t.ty, &text[t.range]))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     tokens,
+    ///     vec![
+    ///         (SemanticTokenType::Comment, "# a"),
+    ///         (SemanticTokenType::Comment, "#"),
+    ///         (SemanticTokenType::Comment, "  # b"),
+    ///     ]
+    /// );
+    /// ```
+    pub fn semantic_tokens(&self) -> Vec<SemanticToken> {
+        let mut tokens = vec![];
+
+        let root = SyntaxNode::new_root(self.green.clone());
+        let mut preorder = root.preorder_with_tokens();
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(element) = event else {
+                continue;
+            };
+
+            let ty = match (element.kind(), &element) {
+                (SyntaxKind::HEADLINE_STARS, _) => SemanticTokenType::HeadlineStars,
+                (SyntaxKind::HEADLINE_KEYWORD_TODO, _) => SemanticTokenType::TodoKeyword,
+                (SyntaxKind::HEADLINE_KEYWORD_DONE, _) => SemanticTokenType::DoneKeyword,
+                (SyntaxKind::HEADLINE_PRIORITY, _) => SemanticTokenType::Priority,
+                (SyntaxKind::HEADLINE_TAGS, _) => SemanticTokenType::Tags,
+                (
+                    SyntaxKind::TIMESTAMP_ACTIVE
+                    | SyntaxKind::TIMESTAMP_INACTIVE
+                    | SyntaxKind::TIMESTAMP_DIARY,
+                    _,
+                ) => SemanticTokenType::Timestamp,
+                (SyntaxKind::LINK, _) => SemanticTokenType::Link,
+                (SyntaxKind::CODE, _) => SemanticTokenType::Code,
+                (SyntaxKind::VERBATIM, _) => SemanticTokenType::Verbatim,
+                (SyntaxKind::MACROS, _) => SemanticTokenType::Macro,
+                (SyntaxKind::ENTITY, _) => SemanticTokenType::Entity,
+                (SyntaxKind::FN_REF, _) => SemanticTokenType::FootnoteReference,
+                (SyntaxKind::TARGET | SyntaxKind::RADIO_TARGET, _) => SemanticTokenType::Target,
+                (SyntaxKind::COOKIE, _) => SemanticTokenType::Cookie,
+                (SyntaxKind::LATEX_FRAGMENT, _) => SemanticTokenType::LatexFragment,
+                (SyntaxKind::SNIPPET, _) => SemanticTokenType::Snippet,
+                (SyntaxKind::INLINE_SRC | SyntaxKind::INLINE_CALL, _) => {
+                    SemanticTokenType::InlineSource
+                }
+                (SyntaxKind::COMMENT, _) => SemanticTokenType::Comment,
+                (
+                    SyntaxKind::KEYWORD | SyntaxKind::AFFILIATED_KEYWORD | SyntaxKind::BABEL_CALL,
+                    _,
+                ) => SemanticTokenType::Keyword,
+                (
+                    SyntaxKind::BLOCK_BEGIN
+                    | SyntaxKind::BLOCK_END
+                    | SyntaxKind::DYN_BLOCK_BEGIN
+                    | SyntaxKind::DYN_BLOCK_END,
+                    _,
+                ) => SemanticTokenType::BlockDelimiter,
+                (SyntaxKind::DRAWER_BEGIN | SyntaxKind::DRAWER_END, _) => {
+                    SemanticTokenType::DrawerDelimiter
+                }
+                (SyntaxKind::LIST_ITEM_BULLET, _) => SemanticTokenType::ListBullet,
+                (SyntaxKind::LIST_ITEM_CHECK_BOX, _) => SemanticTokenType::Checkbox,
+                (SyntaxKind::TEXT, SyntaxElement::Token(token)) => {
+                    match token.parent().map(|p| p.kind()) {
+                        Some(
+                            SyntaxKind::PLANNING_SCHEDULED
+                            | SyntaxKind::PLANNING_DEADLINE
+                            | SyntaxKind::PLANNING_CLOSED,
+                        ) => SemanticTokenType::PlanningKeyword,
+                        Some(kind) => match emphasis_type(kind) {
+                            Some(ty) => ty,
+                            None => continue,
+                        },
+                        None => continue,
+                    }
+                }
+                (_, SyntaxElement::Token(token)) => {
+                    match token.parent().and_then(|p| emphasis_type(p.kind())) {
+                        Some(ty) => ty,
+                        None => continue,
+                    }
+                }
+                _ => continue,
+            };
+
+            if let SyntaxElement::Node(_) = &element {
+                preorder.skip_subtree();
+            }
+
+            let text = element.to_string();
+            let mut start = element.text_range().start();
+            for line in text.split_inclusive('\n') {
+                let len = TextSize::of(line.trim_end());
+                if len > TextSize::default() {
+                    tokens.push(SemanticToken {
+                        range: TextRange::at(start, len),
+                        ty,
+                    });
+                }
+                start += TextSize::of(line);
+            }
+        }
+
+        tokens
+    }
+}
+
+fn emphasis_type(kind: SyntaxKind) -> Option<SemanticTokenType> {
+    match kind {
+        SyntaxKind::BOLD => Some(SemanticTokenType::Bold),
+        SyntaxKind::ITALIC => Some(SemanticTokenType::Italic),
+        SyntaxKind::UNDERLINE => Some(SemanticTokenType::Underline),
+        SyntaxKind::STRIKE => Some(SemanticTokenType::Strike),
+        _ => None,
+    }
+}
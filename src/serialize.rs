@@ -0,0 +1,126 @@
+use rowan::NodeOrToken;
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+use crate::{Org, SyntaxElement, SyntaxNode};
+
+/// Serializable view of a syntax tree, returned by [`Org::serialize_tree`]
+///
+/// Every node is serialized as a map with its kind, its range and its
+/// children, and every token as a map with its kind, its range and its
+/// text. Kinds are the names of [`SyntaxKind`](crate::SyntaxKind) variants.
+///
+/// Since the tree is lossless, concatenating the text of all tokens in
+/// order gives back the source text.
+///
+/// ```rust
+/// use orgize::Org;
+///
+/// let org = Org::parse("*a*");
+/// let json = serde_json::to_value(org.serialize_tree()).unwrap();
+/// assert_eq!(json["kind"], "DOCUMENT");
+/// assert_eq!(json["range"], serde_json::json!([0, 3]));
+///
+/// let bold = &json["children"][0]["children"][0]["children"][0];
+/// assert_eq!(bold["kind"], "BOLD");
+/// assert_eq!(bold["children"][1]["kind"], "TEXT");
+/// assert_eq!(bold["children"][1]["text"], "a");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SerializeTree(SyntaxElement);
+
+impl From<SyntaxNode> for SerializeTree {
+    fn from(node: SyntaxNode) -> Self {
+        SerializeTree(NodeOrToken::Node(node))
+    }
+}
+
+impl From<SyntaxElement> for SerializeTree {
+    fn from(element: SyntaxElement) -> Self {
+        SerializeTree(element)
+    }
+}
+
+impl Serialize for SerializeTree {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let range = self.0.text_range();
+
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("kind", &format!("{:?}", self.0.kind()))?;
+        map.serialize_entry("range", &[u32::from(range.start()), u32::from(range.end())])?;
+        match &self.0 {
+            NodeOrToken::Node(node) => map.serialize_entry("children", &Children(node))?,
+            NodeOrToken::Token(token) => map.serialize_entry("text", token.text())?,
+        }
+        map.end()
+    }
+}
+
+struct Children<'a>(&'a SyntaxNode);
+
+impl Serialize for Children<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(None)?;
+        for child in self.0.children_with_tokens() {
+            seq.serialize_element(&SerializeTree(child))?;
+        }
+        seq.end()
+    }
+}
+
+impl Org {
+    /// Returns a serializable view of the whole syntax tree, see
+    /// [`SerializeTree`]
+    pub fn serialize_tree(&self) -> SerializeTree {
+        SerializeTree::from(self.document().syntax.clone())
+    }
+}
+
+#[test]
+fn headline() {
+    let org = Org::parse("* TODO hello :tag:");
+    let json = serde_json::to_value(org.serialize_tree()).unwrap();
+
+    let headline = &json["children"][0];
+    assert_eq!(headline["kind"], "HEADLINE");
+    assert_eq!(headline["range"], serde_json::json!([0, 18]));
+
+    let kinds = headline["children"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|child| child["kind"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            "HEADLINE_STARS",
+            "WHITESPACE",
+            "HEADLINE_KEYWORD_TODO",
+            "WHITESPACE",
+            "HEADLINE_TITLE",
+            "HEADLINE_TAGS"
+        ]
+    );
+    assert_eq!(headline["children"][2]["text"], "TODO");
+    assert_eq!(headline["children"][4]["children"][0]["text"], "hello ");
+}
+
+#[test]
+fn lossless() {
+    fn concat(value: &serde_json::Value, output: &mut String) {
+        match value.get("text") {
+            Some(text) => output.push_str(text.as_str().unwrap()),
+            None => value["children"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .for_each(|child| concat(child, output)),
+        }
+    }
+
+    let input = "#+TITLE: a\n* TODO b [1/2] :c:\n- [X] *d*\n\n| e | f |\n";
+    let json = serde_json::to_value(Org::parse(input).serialize_tree()).unwrap();
+    let mut output = String::new();
+    concat(&json, &mut output);
+    assert_eq!(output, input);
+}
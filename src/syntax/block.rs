@@ -42,7 +42,9 @@ fn block_node_base(input: Input) -> IResult<Input, GreenElement, ()> {
             children.extend(affiliated_keywords);
             children.push(block_begin);
             children.extend(pre_blank);
-            if kind.is_greater_element() {
+            if contents.is_empty() {
+                children.push(node(BLOCK_CONTENT, []));
+            } else if kind.is_greater_element() {
                 children.push(node(BLOCK_CONTENT, element_nodes(contents)?));
             } else {
                 children.push(node(BLOCK_CONTENT, comma_quoted_text_nodes(contents)));
@@ -56,8 +58,14 @@ fn block_node_base(input: Input) -> IResult<Input, GreenElement, ()> {
     Err(nom::Err::Error(()))
 }
 
-fn block_begin_node(input: Input) -> IResult<Input, (GreenElement, &str), ()> {
-    let (input, (ws1, begin, name)) = tuple((space0, tag_no_case("#+BEGIN_"), alpha1))(input)?;
+fn block_begin_node(input: Input<'_>) -> IResult<Input<'_>, (GreenElement, &str), ()> {
+    // block name is everything until the first whitespace, so `#+END_my`
+    // can't close `#+BEGIN_my-note`
+    let (input, (ws1, begin, name)) = tuple((
+        space0,
+        tag_no_case("#+BEGIN_"),
+        take_while1(|c: char| !c.is_ascii_whitespace()),
+    ))(input)?;
 
     let mut b = NodeBuilder::new();
     b.ws(ws1);
@@ -145,8 +153,13 @@ fn source_block_switches(input: Input) -> IResult<Input, Input, ()> {
 }
 
 fn block_end_node<'a>(input: Input<'a>, name: &str) -> IResult<Input<'a>, GreenElement, ()> {
-    let (input, (ws, end, name, ws_, nl)) =
-        tuple((space0, tag_no_case("#+END_"), tag(name), space0, eol_or_eof))(input)?;
+    let (input, (ws, end, name, ws_, nl)) = tuple((
+        space0,
+        tag_no_case("#+END_"),
+        tag_no_case(name),
+        space0,
+        eol_or_eof,
+    ))(input)?;
 
     let mut b = NodeBuilder::new();
     b.ws(ws);
@@ -301,5 +314,58 @@ alert('Hello World!');
     "###
     );
 
+    // block names are case-insensitive, and BEGIN/END don't have to agree on case
+    insta::assert_debug_snapshot!(
+        to_src_block("#+begin_src rust\nfn main() {}\n#+END_SRC").syntax,
+        @r###"
+    SOURCE_BLOCK@0..39
+      BLOCK_BEGIN@0..17
+        TEXT@0..8 "#+begin_"
+        TEXT@8..11 "src"
+        WHITESPACE@11..12 " "
+        SRC_BLOCK_LANGUAGE@12..16 "rust"
+        NEW_LINE@16..17 "\n"
+      BLOCK_CONTENT@17..30
+        TEXT@17..30 "fn main() {}\n"
+      BLOCK_END@30..39
+        TEXT@30..36 "#+END_"
+        TEXT@36..39 "SRC"
+    "###
+    );
+
+    let config = &crate::ParseConfig::default();
+    assert!(block_node(("#+Begin_Quote\n#+end_QUOTE", config).into()).is_ok());
+    assert!(block_node(("#+begin_quote\n#+end_src", config).into()).is_err());
+
+    // mismatched end lines don't close the block, nor are they part of it
+    assert!(block_node(("#+BEGIN_QUOTE\na\n#+END_VERSE\nb\n", config).into()).is_err());
+    assert!(block_node(("#+BEGIN_my-note\na\n#+END_my\n", config).into()).is_err());
+    assert!(block_node(("#+BEGIN_SRC\na\n#+END_SRCX\n", config).into()).is_err());
+    assert!(block_node(("#+BEGIN_EXAMPLE\na\n#+END_EXAMPLE text\n", config).into()).is_err());
+    let (rest, _) =
+        block_node(("#+BEGIN_QUOTE\n#+END_VERSE\n#+END_QUOTE\nc", config).into()).unwrap();
+    assert_eq!(rest.as_str(), "c");
+
+    insta::assert_debug_snapshot!(
+        to_ast::<crate::ast::SpecialBlock>(block_node)("#+begin_my-note\n#+end_my\n#+END_MY-NOTE").syntax,
+        @r###"
+    SPECIAL_BLOCK@0..38
+      BLOCK_BEGIN@0..16
+        TEXT@0..8 "#+begin_"
+        TEXT@8..15 "my-note"
+        NEW_LINE@15..16 "\n"
+      BLOCK_CONTENT@16..25
+        PARAGRAPH@16..25
+          TEXT@16..21 "#+end"
+          SUBSCRIPT@21..24
+            UNDERSCORE@21..22 "_"
+            TEXT@22..24 "my"
+          TEXT@24..25 "\n"
+      BLOCK_END@25..38
+        TEXT@25..31 "#+END_"
+        TEXT@31..38 "MY-NOTE"
+    "###
+    );
+
     // TODO: more testing
 }
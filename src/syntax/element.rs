@@ -89,7 +89,8 @@ pub fn element_node(input: Input) -> IResult<Input, GreenElement, ()> {
         Some(b'[') => fn_def_node(input),
         Some(b'0'..=b'9') | Some(b'*') => list_node(input),
         // clock doesn't have affiliated keywords
-        Some(b'C') if !has_affiliated_keyword => clock_node(input),
+        Some(b'C') if !has_affiliated_keyword => clock_node(input).or_else(|_| list_node(input)),
+        Some(b'a'..=b'z' | b'A'..=b'Z') if input.c.alphabetical_lists => list_node(input),
         Some(b'-') => rule_node(input).or_else(|_| list_node(input)),
         Some(b':') => drawer_node(input).or_else(|_| fixed_width_node(input)),
         Some(b'|') => org_table_node(input),
@@ -103,12 +104,30 @@ pub fn element_node(input: Input) -> IResult<Input, GreenElement, ()> {
     };
 
     if has_affiliated_keyword {
-        result.or_else(|_| paragraph_node(input))
+        result.or_else(|_| affiliated_paragraph_node(input, i))
     } else {
         result
     }
 }
 
+/// Recognizes a paragraph with affiliated keywords
+///
+/// `contents` is the input after affiliated keywords. Like other paragraphs,
+/// it ends before the next element even without a blank line.
+fn affiliated_paragraph_node<'a>(
+    input: Input<'a>,
+    contents: Input<'a>,
+) -> IResult<Input<'a>, GreenElement, ()> {
+    let end = ElementPositions::new(contents)
+        .filter(|(_, head)| !head.is_empty())
+        .find(|(rest, _)| element_node(*rest).is_ok())
+        .map_or(input.len(), |(rest, _)| input.len() - rest.len());
+
+    let (_, paragraph) = input.take_split(end);
+    let (rest, node) = paragraph_node(paragraph)?;
+    Ok((input.take_split(end - rest.len()).0, node))
+}
+
 struct ElementPositions<'a> {
     input: Input<'a>,
     pos: usize,
@@ -138,7 +157,8 @@ impl<'a> Iterator for ElementPositions<'a> {
             if matches!(
                 b,
                 b'[' | b'0'..=b'9' | b'*' | b'C' | b'-' | b':' | b'|' | b'+' | b'#' | b'\\'
-            ) {
+            ) || (self.input.c.alphabetical_lists && b.is_ascii_alphabetic())
+            {
                 let previous = self.pos;
                 self.pos = iter
                     .next()
@@ -215,12 +235,67 @@ b"#),
             TEXT@8..12 "cell"
     "###
     );
+
+    // paragraph ends before an element even without blank line
+    insta::assert_debug_snapshot!(
+        t("a\n- b\n| c |\n#+BEGIN_SRC\n#+END_SRC\n#+TITLE: d\n  e\n"),
+        @r###"
+    SECTION@0..49
+      PARAGRAPH@0..2
+        TEXT@0..2 "a\n"
+      LIST@2..6
+        LIST_ITEM@2..6
+          LIST_ITEM_INDENT@2..2 ""
+          LIST_ITEM_BULLET@2..4 "- "
+          LIST_ITEM_CONTENT@4..6
+            PARAGRAPH@4..6
+              TEXT@4..6 "b\n"
+      ORG_TABLE@6..12
+        ORG_TABLE_STANDARD_ROW@6..12
+          PIPE@6..7 "|"
+          WHITESPACE@7..8 " "
+          ORG_TABLE_CELL@8..9
+            TEXT@8..9 "c"
+          WHITESPACE@9..10 " "
+          PIPE@10..11 "|"
+          WHITESPACE@11..12 "\n"
+      SOURCE_BLOCK@12..34
+        BLOCK_BEGIN@12..24
+          TEXT@12..20 "#+BEGIN_"
+          TEXT@20..23 "SRC"
+          NEW_LINE@23..24 "\n"
+        BLOCK_CONTENT@24..24
+        BLOCK_END@24..34
+          TEXT@24..30 "#+END_"
+          TEXT@30..33 "SRC"
+          NEW_LINE@33..34 "\n"
+      KEYWORD@34..45
+        HASH_PLUS@34..36 "#+"
+        TEXT@36..41 "TITLE"
+        COLON@41..42 ":"
+        TEXT@42..44 " d"
+        NEW_LINE@44..45 "\n"
+      PARAGRAPH@45..49
+        TEXT@45..49 "  e\n"
+    "###
+    );
+
+    // indented line continues the paragraph
+    insta::assert_debug_snapshot!(
+        t("a\n  b\n"),
+        @r###"
+    SECTION@0..6
+      PARAGRAPH@0..6
+        TEXT@0..6 "a\n  b\n"
+    "###
+    );
 }
 
 #[test]
 fn affiliated_keywords() {
     use crate::syntax::{SyntaxKind, SyntaxNode};
     use crate::{syntax::combinator::node, ParseConfig};
+    use rowan::ast::AstNode;
 
     let t = |input: &str| {
         let config = &ParseConfig::default();
@@ -247,6 +322,38 @@ fn affiliated_keywords() {
     "###
     );
 
+    // affiliated keywords are case-insensitive
+    insta::assert_debug_snapshot!(
+        t("#+caption: abc\n#+attr_html: :width 300px\n[[./img/a.jpg]]"),
+        @r###"
+    SECTION@0..56
+      PARAGRAPH@0..56
+        AFFILIATED_KEYWORD@0..15
+          HASH_PLUS@0..2 "#+"
+          TEXT@2..9 "caption"
+          COLON@9..10 ":"
+          TEXT@10..14 " abc"
+          NEW_LINE@14..15 "\n"
+        AFFILIATED_KEYWORD@15..41
+          HASH_PLUS@15..17 "#+"
+          TEXT@17..26 "attr_html"
+          COLON@26..27 ":"
+          TEXT@27..40 " :width 300px"
+          NEW_LINE@40..41 "\n"
+        LINK@41..56
+          L_BRACKET2@41..43 "[["
+          LINK_PATH@43..54 "./img/a.jpg"
+          R_BRACKET2@54..56 "]]"
+    "###
+    );
+
+    let section = t("#+caption: abc\n#+attr_html: :width 300px\n[[./img/a.jpg]]");
+    let paragraph = crate::ast::Paragraph::cast(section.first_child().unwrap()).unwrap();
+    assert!(paragraph.caption().is_some());
+    assert!(paragraph.attr("html").is_some());
+    assert!(paragraph.attr("HTML").is_some());
+    assert!(paragraph.attr("latex").is_none());
+
     // affiliated keywords + blank lines, fallback to normal keyword
     insta::assert_debug_snapshot!(
         t("#+ATTR_HTML: :width 300px\n#+CAPTION: abc\n\n[[./img/a.jpg]]"),
@@ -290,7 +397,9 @@ fn affiliated_keywords() {
         COLON@29..30 ":"
         TEXT@30..34 "WORD"
         R_BRACKET@34..35 "]"
-        TEXT@35..55 " https://orgmode.org"
+        TEXT@35..36 " "
+        LINK@36..55
+          LINK_PATH@36..55 "https://orgmode.org"
     "###
     );
 
@@ -336,4 +445,52 @@ fn affiliated_keywords() {
         TEXT@10..26 " Longer caption."
     "###
     );
+
+    // paragraph with affiliated keywords ends before the next element
+    insta::assert_debug_snapshot!(
+        t("#+NAME: a\nb\n- c\n"),
+        @r###"
+    SECTION@0..16
+      PARAGRAPH@0..12
+        AFFILIATED_KEYWORD@0..10
+          HASH_PLUS@0..2 "#+"
+          TEXT@2..6 "NAME"
+          COLON@6..7 ":"
+          TEXT@7..9 " a"
+          NEW_LINE@9..10 "\n"
+        TEXT@10..12 "b\n"
+      LIST@12..16
+        LIST_ITEM@12..16
+          LIST_ITEM_INDENT@12..12 ""
+          LIST_ITEM_BULLET@12..14 "- "
+          LIST_ITEM_CONTENT@14..16
+            PARAGRAPH@14..16
+              TEXT@14..16 "c\n"
+    "###
+    );
+
+    insta::assert_debug_snapshot!(
+        t("#+NAME: a\nb\n\n| c |\n"),
+        @r###"
+    SECTION@0..19
+      PARAGRAPH@0..13
+        AFFILIATED_KEYWORD@0..10
+          HASH_PLUS@0..2 "#+"
+          TEXT@2..6 "NAME"
+          COLON@6..7 ":"
+          TEXT@7..9 " a"
+          NEW_LINE@9..10 "\n"
+        TEXT@10..12 "b\n"
+        BLANK_LINE@12..13 "\n"
+      ORG_TABLE@13..19
+        ORG_TABLE_STANDARD_ROW@13..19
+          PIPE@13..14 "|"
+          WHITESPACE@14..15 " "
+          ORG_TABLE_CELL@15..16
+            TEXT@15..16 "c"
+          WHITESPACE@16..17 " "
+          PIPE@17..18 "|"
+          WHITESPACE@18..19 "\n"
+    "###
+    );
 }
@@ -142,7 +142,11 @@ pub fn verify_pre(input: &str) -> bool {
 
 #[test]
 fn parse() {
-    use crate::{ast::Bold, tests::to_ast, ParseConfig};
+    use crate::{
+        ast::{Bold, Code, Verbatim},
+        tests::to_ast,
+        ParseConfig,
+    };
 
     let to_bold = to_ast::<Bold>(bold_node);
 
@@ -176,6 +180,30 @@ fn parse() {
     "###
     );
 
+    // contents of verbatim and code are not parsed
+    let to_verbatim = to_ast::<Verbatim>(verbatim_node);
+    let to_code = to_ast::<Code>(code_node);
+
+    insta::assert_debug_snapshot!(
+        to_verbatim("=*a* /b/ <c> & \\alpha=").syntax,
+        @r###"
+    VERBATIM@0..22
+      EQUAL@0..1 "="
+      TEXT@1..21 "*a* /b/ <c> & \\alpha"
+      EQUAL@21..22 "="
+    "###
+    );
+
+    insta::assert_debug_snapshot!(
+        to_code("~a < b~").syntax,
+        @r###"
+    CODE@0..7
+      TILDE@0..1 "~"
+      TEXT@1..6 "a < b"
+      TILDE@6..7 "~"
+    "###
+    );
+
     let config = &ParseConfig::default();
 
     assert!(bold_node(("*bold*a", config).into()).is_err());
@@ -184,3 +212,83 @@ fn parse() {
     assert!(bold_node(("*b\nol\nd*", config).into()).is_err());
     assert!(italic_node(("*bold*", config).into()).is_err());
 }
+
+#[test]
+fn nested() {
+    use crate::{
+        ast::{Bold, Code, Italic, Verbatim},
+        tests::to_ast,
+    };
+
+    let to_bold = to_ast::<Bold>(bold_node);
+    let to_italic = to_ast::<Italic>(italic_node);
+    let to_verbatim = to_ast::<Verbatim>(verbatim_node);
+    let to_code = to_ast::<Code>(code_node);
+
+    insta::assert_debug_snapshot!(
+        to_bold("*bold /italic/ bold*").syntax,
+        @r###"
+    BOLD@0..20
+      STAR@0..1 "*"
+      TEXT@1..6 "bold "
+      ITALIC@6..14
+        SLASH@6..7 "/"
+        TEXT@7..13 "italic"
+        SLASH@13..14 "/"
+      TEXT@14..19 " bold"
+      STAR@19..20 "*"
+    "###
+    );
+
+    insta::assert_debug_snapshot!(
+        to_italic("/a _b +c+ b_ a/").syntax,
+        @r###"
+    ITALIC@0..15
+      SLASH@0..1 "/"
+      TEXT@1..3 "a "
+      UNDERLINE@3..12
+        UNDERSCORE@3..4 "_"
+        TEXT@4..6 "b "
+        STRIKE@6..9
+          PLUS@6..7 "+"
+          TEXT@7..8 "c"
+          PLUS@8..9 "+"
+        TEXT@9..11 " b"
+        UNDERSCORE@11..12 "_"
+      TEXT@12..14 " a"
+      SLASH@14..15 "/"
+    "###
+    );
+
+    // verbatim and code contents are not parsed
+    insta::assert_debug_snapshot!(
+        to_verbatim("=*not* bold=").syntax,
+        @r###"
+    VERBATIM@0..12
+      EQUAL@0..1 "="
+      TEXT@1..11 "*not* bold"
+      EQUAL@11..12 "="
+    "###
+    );
+
+    insta::assert_debug_snapshot!(
+        to_code("~/x/~").syntax,
+        @r###"
+    CODE@0..5
+      TILDE@0..1 "~"
+      TEXT@1..4 "/x/"
+      TILDE@4..5 "~"
+    "###
+    );
+
+    // the closing marker of inner emphasis must come before the outer one
+    insta::assert_debug_snapshot!(
+        to_bold("*a /b* c/").syntax,
+        @r###"
+    BOLD@0..6
+      STAR@0..1 "*"
+      TEXT@1..5 "a /b"
+      STAR@5..6 "*"
+    "###
+    );
+}
@@ -12,6 +12,7 @@ use super::{
     },
     input::Input,
     keyword::affiliated_keyword_nodes,
+    object::standard_object_nodes,
     SyntaxKind,
 };
 
@@ -48,7 +49,9 @@ pub fn fn_def_node(input: Input) -> IResult<Input, GreenElement, ()> {
             b.push(colon);
             b.text(label);
             b.push(r_bracket);
-            b.text(content);
+            if !content.is_empty() {
+                b.children.extend(standard_object_nodes(content));
+            }
             b.ws(ws_);
             b.nl(nl);
             b.children.extend(post_blank);
@@ -74,7 +77,9 @@ fn parse() {
       COLON@3..4 ":"
       TEXT@4..5 "1"
       R_BRACKET@5..6 "]"
-      TEXT@6..26 " https://orgmode.org"
+      TEXT@6..7 " "
+      LINK@7..26
+        LINK_PATH@7..26 "https://orgmode.org"
     "###
     );
 
@@ -87,7 +92,9 @@ fn parse() {
       COLON@3..4 ":"
       TEXT@4..10 "word_1"
       R_BRACKET@10..11 "]"
-      TEXT@11..31 " https://orgmode.org"
+      TEXT@11..12 " "
+      LINK@12..31
+        LINK_PATH@12..31 "https://orgmode.org"
     "###
     );
 
@@ -100,7 +107,9 @@ fn parse() {
       COLON@3..4 ":"
       TEXT@4..10 "WORD-1"
       R_BRACKET@10..11 "]"
-      TEXT@11..31 " https://orgmode.org"
+      TEXT@11..12 " "
+      LINK@12..31
+        LINK_PATH@12..31 "https://orgmode.org"
     "###
     );
 
@@ -151,7 +160,9 @@ fn parse() {
       COLON@17..18 ":"
       TEXT@18..24 "WORD-1"
       R_BRACKET@24..25 "]"
-      TEXT@25..45 " https://orgmode.org"
+      TEXT@25..26 " "
+      LINK@26..45
+        LINK_PATH@26..45 "https://orgmode.org"
     "###
     );
 }
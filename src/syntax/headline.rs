@@ -19,6 +19,7 @@ use super::{
     planning::planning_node,
     SyntaxKind::*,
 };
+use crate::escape::is_tag_char;
 
 #[cfg_attr(
     feature = "tracing",
@@ -134,7 +135,7 @@ fn headline_stars(input: Input) -> IResult<Input, Input, ()> {
     let bytes = input.as_bytes();
     let level = bytes.iter().take_while(|&&c| c == b'*').count();
 
-    if level == 0 {
+    if level == 0 || (input.c.odd_levels_only && level % 2 == 0) {
         Err(nom::Err::Error(()))
     }
     // headline stars must be followed by space
@@ -170,11 +171,7 @@ fn headline_tags_node(input: Input) -> IResult<Input, GreenElement, ()> {
             can_not_be_ws = false;
             debug_assert!(i > ii, "{} > {}", i, ii);
             i = ii;
-        } else if String::from_utf8_lossy(item)
-            .chars()
-            // https://github.com/yyr/org-mode/blob/d8494b5668ad4d4e68e83228ae8451eaa01d2220/lisp/org-element.el#L922C25-L922C32
-            .all(|c| c.is_alphanumeric() || c == '_' || c == '@' || c == '#' || c == '%')
-        {
+        } else if String::from_utf8_lossy(item).chars().all(is_tag_char) {
             children.push(input.slice(ii + 1..i).text_token());
             children.push(token(COLON, ":"));
             can_not_be_ws = false;
@@ -323,6 +320,44 @@ fn parse() {
     assert!(headline_node(("**\t", config).into()).is_err());
 }
 
+#[test]
+fn title_objects() {
+    use crate::{ast::Headline, tests::to_ast};
+
+    let to_headline = to_ast::<Headline>(headline_node);
+
+    insta::assert_debug_snapshot!(
+        to_headline("* [fn:1] [[link]] *bold* :tag:").syntax,
+        @r###"
+    HEADLINE@0..30
+      HEADLINE_STARS@0..1 "*"
+      WHITESPACE@1..2 " "
+      HEADLINE_TITLE@2..25
+        FN_REF@2..8
+          L_BRACKET@2..3 "["
+          TEXT@3..5 "fn"
+          COLON@5..6 ":"
+          TEXT@6..7 "1"
+          R_BRACKET@7..8 "]"
+        TEXT@8..9 " "
+        LINK@9..17
+          L_BRACKET2@9..11 "[["
+          LINK_PATH@11..15 "link"
+          R_BRACKET2@15..17 "]]"
+        TEXT@17..18 " "
+        BOLD@18..24
+          STAR@18..19 "*"
+          TEXT@19..23 "bold"
+          STAR@23..24 "*"
+        TEXT@24..25 " "
+      HEADLINE_TAGS@25..30
+        COLON@25..26 ":"
+        TEXT@26..29 "tag"
+        COLON@29..30 ":"
+    "###
+    );
+}
+
 #[test]
 fn issue_15_16() {
     use crate::{ast::Headline, tests::to_ast};
@@ -367,3 +402,67 @@ fn issue_15_16() {
         tags.map(|x| x.to_string()).collect::<Vec<_>>(),
     );
 }
+
+#[test]
+fn crlf() {
+    use crate::{ast::Headline, tests::to_ast, ParseConfig};
+
+    let to_headline = to_ast::<Headline>(headline_node);
+
+    let hdl = to_headline("* TODO foo :t:\r\n");
+    insta::assert_debug_snapshot!(hdl.syntax, @r###"
+    HEADLINE@0..16
+      HEADLINE_STARS@0..1 "*"
+      WHITESPACE@1..2 " "
+      HEADLINE_KEYWORD_TODO@2..6 "TODO"
+      WHITESPACE@6..7 " "
+      HEADLINE_TITLE@7..11
+        TEXT@7..11 "foo "
+      HEADLINE_TAGS@11..14
+        COLON@11..12 ":"
+        TEXT@12..13 "t"
+        COLON@13..14 ":"
+      NEW_LINE@14..16 "\r\n"
+    "###);
+    assert_eq!(hdl.title_raw(), "foo ");
+    assert_eq!(hdl.tags().map(|t| t.to_string()).collect::<Vec<_>>(), ["t"]);
+
+    let hdl = to_headline("* foo\r\n\r\n** bar :a:b:  \r\ntext\r\n");
+    insta::assert_debug_snapshot!(hdl.syntax, @r###"
+    HEADLINE@0..31
+      HEADLINE_STARS@0..1 "*"
+      WHITESPACE@1..2 " "
+      HEADLINE_TITLE@2..5
+        TEXT@2..5 "foo"
+      NEW_LINE@5..7 "\r\n"
+      SECTION@7..9
+        PARAGRAPH@7..9
+          BLANK_LINE@7..9 "\r\n"
+      HEADLINE@9..31
+        HEADLINE_STARS@9..11 "**"
+        WHITESPACE@11..12 " "
+        HEADLINE_TITLE@12..16
+          TEXT@12..16 "bar "
+        HEADLINE_TAGS@16..21
+          COLON@16..17 ":"
+          TEXT@17..18 "a"
+          COLON@18..19 ":"
+          TEXT@19..20 "b"
+          COLON@20..21 ":"
+        WHITESPACE@21..23 "  "
+        NEW_LINE@23..25 "\r\n"
+        SECTION@25..31
+          PARAGRAPH@25..31
+            TEXT@25..31 "text\r\n"
+    "###);
+    assert_eq!(hdl.title_raw(), "foo");
+    assert_eq!(
+        hdl.syntax.to_string(),
+        "* foo\r\n\r\n** bar :a:b:  \r\ntext\r\n"
+    );
+
+    let config = ParseConfig::default();
+    let org = config.parse("* foo\r* bar\r");
+    assert_eq!(org.headlines().count(), 2);
+    assert_eq!(org.to_org(), "* foo\r* bar\r");
+}
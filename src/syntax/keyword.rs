@@ -2,7 +2,7 @@
 
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_till, take_while1},
+    bytes::complete::{tag, tag_no_case, take_till, take_while1},
     character::complete::space0,
     combinator::{recognize, verify},
     sequence::tuple,
@@ -12,6 +12,7 @@ use nom::{
 use super::{
     combinator::{blank_lines, hash_plus_token, node, trim_line_end, GreenElement},
     input::Input,
+    object::standard_object_nodes,
     SyntaxKind,
 };
 
@@ -21,13 +22,13 @@ use super::{
 )]
 pub fn keyword_node(input: Input) -> IResult<Input, GreenElement, ()> {
     fn f(input: Input) -> IResult<Input, GreenElement, ()> {
-        let (input, (key, mut nodes)) = keyword_node_base(input)?;
+        let (input, (key, mut nodes)) = keyword_node_base(input, false)?;
         let (input, post_blank) = blank_lines(input)?;
         nodes.extend(post_blank);
         Ok((
             input,
             node(
-                if key == "CALL" {
+                if key.eq_ignore_ascii_case("CALL") {
                     SyntaxKind::BABEL_CALL
                 } else {
                     SyntaxKind::KEYWORD
@@ -50,7 +51,7 @@ pub fn affiliated_keyword_nodes(input: Input) -> IResult<Input, Vec<GreenElement
     let mut i = input;
 
     while !i.is_empty() {
-        let Ok((input_, (key, nodes))) = keyword_node_base(i) else {
+        let Ok((input_, (key, nodes))) = keyword_node_base(i, true) else {
             break;
         };
 
@@ -61,7 +62,13 @@ pub fn affiliated_keyword_nodes(input: Input) -> IResult<Input, Vec<GreenElement
             return Ok((input, vec![]));
         }
 
-        if input_.c.affiliated_keywords.iter().all(|w| w != key) && !key.starts_with("ATTR_") {
+        if !input_
+            .c
+            .affiliated_keywords
+            .iter()
+            .any(|w| w.eq_ignore_ascii_case(key))
+            && !is_attr_key(key)
+        {
             break;
         }
 
@@ -78,7 +85,7 @@ pub fn tblfm_keyword_nodes(input: Input) -> IResult<Input, Vec<GreenElement>, ()
     let mut i = input;
 
     while !i.is_empty() {
-        let Ok((input, (key, nodes))) = keyword_node_base(i) else {
+        let Ok((input, (key, nodes))) = keyword_node_base(i, false) else {
             break;
         };
 
@@ -94,7 +101,11 @@ pub fn tblfm_keyword_nodes(input: Input) -> IResult<Input, Vec<GreenElement>, ()
     Ok((i, children))
 }
 
-fn keyword_node_base(input: Input) -> IResult<Input, (&str, Vec<GreenElement>), ()> {
+/// Parses value as objects if `affiliated` is true and key is one of `parsed_keywords`
+fn keyword_node_base(
+    input: Input<'_>,
+    affiliated: bool,
+) -> IResult<Input<'_>, (&str, Vec<GreenElement>), ()> {
     let (input, (ws, hash_plus)) = tuple((space0, hash_plus_token))(input)?;
 
     let (input, (key, optional, colon)) = alt((key_with_optional, key))(input)?;
@@ -113,7 +124,18 @@ fn keyword_node_base(input: Input) -> IResult<Input, (&str, Vec<GreenElement>),
         children.push(r_bracket.token(SyntaxKind::R_BRACKET));
     }
     children.push(colon.token(SyntaxKind::COLON));
-    children.push(value.text_token());
+    if affiliated
+        && !value.is_empty()
+        && value
+            .c
+            .parsed_keywords
+            .iter()
+            .any(|w| w.eq_ignore_ascii_case(key.s))
+    {
+        children.extend(standard_object_nodes(value));
+    } else {
+        children.push(value.text_token());
+    }
     if !ws_.is_empty() {
         children.push(ws_.ws_token());
     }
@@ -124,6 +146,14 @@ fn keyword_node_base(input: Input) -> IResult<Input, (&str, Vec<GreenElement>),
     Ok((input, (key.s, children)))
 }
 
+/// `#+ATTR_BACKEND:` keyword, matched case-insensitively
+fn is_attr_key(key: &str) -> bool {
+    key.len() > 5
+        && key
+            .get(..5)
+            .is_some_and(|k| k.eq_ignore_ascii_case("ATTR_"))
+}
+
 fn key(input: Input) -> IResult<Input, (Input, Option<(Input, Input, Input)>, Input), ()> {
     let (input, output) = verify(
         recognize(tuple((
@@ -140,7 +170,7 @@ fn key_with_optional(
     input: Input,
 ) -> IResult<Input, (Input, Option<(Input, Input, Input)>, Input), ()> {
     let (input, (key, r_backer, optional, l_backer, colon)) = tuple((
-        alt((tag("CAPTION"), tag("RESULTS"))),
+        alt((tag_no_case("CAPTION"), tag_no_case("RESULTS"))),
         tag("["),
         take_till(|c| c == '\r' || c == '\n' || c == ']'),
         tag("]"),
@@ -283,6 +313,31 @@ fn parse() {
     "###
     );
 
+    insta::assert_debug_snapshot!(
+        to_babel_call("#+call: double(n=4)").syntax,
+        @r###"
+    BABEL_CALL@0..19
+      HASH_PLUS@0..2 "#+"
+      TEXT@2..6 "call"
+      COLON@6..7 ":"
+      TEXT@7..19 " double(n=4)"
+    "###
+    );
+
+    insta::assert_debug_snapshot!(
+        to_keyword("#+caption[short]: value").syntax,
+        @r###"
+    KEYWORD@0..23
+      HASH_PLUS@0..2 "#+"
+      TEXT@2..9 "caption"
+      L_BRACKET@9..10 "["
+      TEXT@10..15 "short"
+      R_BRACKET@15..16 "]"
+      COLON@16..17 ":"
+      TEXT@17..23 " value"
+    "###
+    );
+
     let config = &ParseConfig::default();
 
     assert!(keyword_node(("#+KE Y: VALUE", config).into()).is_err());
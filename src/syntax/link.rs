@@ -1,13 +1,14 @@
 use nom::{
-    bytes::complete::take_while,
-    combinator::{map, opt},
+    bytes::complete::{take_while, take_while1},
+    combinator::{map, opt, verify},
     sequence::tuple,
-    IResult,
+    IResult, InputTake,
 };
 
 use super::{
     combinator::{
-        l_bracket2_token, l_bracket_token, node, r_bracket2_token, r_bracket_token, GreenElement,
+        l_angle_token, l_bracket2_token, l_bracket_token, node, r_angle_token, r_bracket2_token,
+        r_bracket_token, token, GreenElement,
     },
     input::Input,
     object::link_description_object_nodes,
@@ -46,6 +47,115 @@ pub fn link_node(input: Input) -> IResult<Input, GreenElement, ()> {
     crate::lossless_parser!(parser, input)
 }
 
+/// Link types recognized in angle links and plain links
+const LINK_TYPES: &[&str] = &[
+    "attachment",
+    "doi",
+    "elisp",
+    "file",
+    "ftp",
+    "help",
+    "http",
+    "https",
+    "id",
+    "info",
+    "irc",
+    "mailto",
+    "news",
+    "shell",
+];
+
+pub(crate) fn has_link_type(path: &str) -> bool {
+    link_type(path).is_some()
+}
+
+/// Returns the recognized link type of given path, like `https` or `file`
+pub(crate) fn link_type(path: &str) -> Option<&'static str> {
+    let (ty, rest) = path.split_once(':')?;
+    if rest.strip_prefix("//").unwrap_or(rest).is_empty() {
+        return None;
+    }
+    LINK_TYPES.iter().find(|t| **t == ty).copied()
+}
+
+/// Recognizes an angle link, like `<https://orgmode.org>`
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(input), fields(input = input.s))
+)]
+pub fn angle_link_node(input: Input) -> IResult<Input, GreenElement, ()> {
+    let mut parser = map(
+        tuple((
+            l_angle_token,
+            verify(
+                take_while1(|c: char| !matches!(c, '<' | '>' | '[' | ']' | '\n')),
+                |path: &Input| has_link_type(path.as_str()),
+            ),
+            r_angle_token,
+        )),
+        |(l_angle, path, r_angle)| node(LINK, [l_angle, path.token(LINK_PATH), r_angle]),
+    );
+    crate::lossless_parser!(parser, input)
+}
+
+/// Recognizes a plain link, like `https://orgmode.org`
+///
+/// `input` starts at the colon following link type, and link type is the
+/// trailing word of `pre`. So the returned node is longer than the consumed
+/// input, and caller must exclude link type from the preceding text.
+pub fn plain_link_node<'a>(
+    input: Input<'a>,
+    pre: Input<'a>,
+) -> IResult<Input<'a>, GreenElement, ()> {
+    let ty_start = pre
+        .as_str()
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !c.is_alphanumeric())
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    let ty = &pre.as_str()[ty_start..];
+
+    if !LINK_TYPES.contains(&ty) {
+        return Err(nom::Err::Error(()));
+    }
+
+    // skip the colon
+    let path = &input.as_str()[1..];
+
+    let mut depth = 0;
+    let mut end = path.len();
+    for (idx, c) in path.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth > 0 => depth -= 1,
+            c if c.is_whitespace() || matches!(c, ')' | '<' | '>' | '[' | ']' | '"') => {
+                end = idx;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    // trailing punctuation isn't part of the link, except slashes and
+    // balanced parentheses
+    let end = path[..end]
+        .trim_end_matches(|c: char| c.is_ascii_punctuation() && c != '/' && c != ')')
+        .len();
+
+    if path[..end]
+        .strip_prefix("//")
+        .unwrap_or(&path[..end])
+        .is_empty()
+    {
+        return Err(nom::Err::Error(()));
+    }
+
+    let (input, path) = input.take_split(end + 1);
+    let link = node(LINK, [token(LINK_PATH, &format!("{ty}{}", path.as_str()))]);
+
+    Ok((input, link))
+}
+
 #[test]
 fn parse() {
     use crate::{ast::Link, tests::to_ast, ParseConfig};
@@ -110,3 +220,74 @@ fn parse() {
 
     assert!(link_node(("[[#id][desc]", config).into()).is_err());
 }
+
+#[test]
+fn plain_and_angle_links() {
+    use crate::{
+        syntax::{combinator::node, object::standard_object_nodes, SyntaxKind, SyntaxNode},
+        ParseConfig,
+    };
+
+    let t = |input: &str| {
+        let config = &ParseConfig::default();
+        let children = standard_object_nodes((input, config).into());
+        SyntaxNode::new_root(node(SyntaxKind::PARAGRAPH, children).into_node().unwrap())
+    };
+
+    insta::assert_debug_snapshot!(
+        t("see https://orgmode.org/manual/Link-Format.html, or <mailto:a@b.c>."),
+        @r###"
+    PARAGRAPH@0..67
+      TEXT@0..4 "see "
+      LINK@4..47
+        LINK_PATH@4..47 "https://orgmode.org/m ..."
+      TEXT@47..52 ", or "
+      LINK@52..66
+        L_ANGLE@52..53 "<"
+        LINK_PATH@53..65 "mailto:a@b.c"
+        R_ANGLE@65..66 ">"
+      TEXT@66..67 "."
+    "###
+    );
+
+    insta::assert_debug_snapshot!(
+        t("(https://en.wikipedia.org/wiki/Org_(disambiguation)) file:a_b.org"),
+        @r###"
+    PARAGRAPH@0..65
+      TEXT@0..1 "("
+      LINK@1..51
+        LINK_PATH@1..51 "https://en.wikipedia. ..."
+      TEXT@51..53 ") "
+      LINK@53..65
+        LINK_PATH@53..65 "file:a_b.org"
+    "###
+    );
+
+    // not a link type, or not at word boundary
+    insta::assert_debug_snapshot!(
+        t("TODO: 10:00 xhttp://a <https:> <https://> <foo:bar>"),
+        @r###"
+    PARAGRAPH@0..51
+      TEXT@0..51 "TODO: 10:00 xhttp://a ..."
+    "###
+    );
+
+    // non-ascii characters before the colon
+    insta::assert_debug_snapshot!(
+        t("Résumé: éhttps://orgmode.org 中文https://a.b —https://orgmode.org"),
+        @r###"
+    PARAGRAPH@0..72
+      TEXT@0..53 "Résumé: éhttps://o ..."
+      LINK@53..72
+        LINK_PATH@53..72 "https://orgmode.org"
+    "###
+    );
+    for input in [
+        "* é:t:",
+        "x éhttps://orgmode.org y",
+        "—https://orgmode.org",
+        "* 中文https://a.b",
+    ] {
+        crate::Org::parse(input);
+    }
+}
@@ -1,4 +1,4 @@
-use memchr::{memchr, memchr2};
+use memchr::memchr;
 use nom::{
     branch::alt,
     bytes::complete::{tag, take},
@@ -87,6 +87,7 @@ fn list_item_node<'a>(
             tag("-"),
             preceded(digit1, tag(".")),
             preceded(digit1, tag(")")),
+            preceded(alphabetical_counter, alt((tag("."), tag(")")))),
         )),
         alt((space1, eol_or_eof)),
     )))(input)?;
@@ -112,7 +113,7 @@ fn list_item_node<'a>(
         ));
     }
 
-    let is_ordered = bullet.s.starts_with(|c: char| c.is_ascii_digit());
+    let is_ordered = bullet.s.starts_with(|c: char| c.is_ascii_alphanumeric());
     let (input, counter) = opt(list_item_counter)(input)?;
     let (input, checkbox) = opt(list_item_checkbox)(input)?;
     let (input, tag) = cond(!is_ordered, opt(list_item_tag))(input)?;
@@ -146,6 +147,16 @@ fn list_item_node<'a>(
     ))
 }
 
+/// Recognizes a single letter, if alphabetical bullets are enabled
+fn alphabetical_counter(input: Input) -> IResult<Input, Input, ()> {
+    if !input.c.alphabetical_lists {
+        return Err(nom::Err::Error(()));
+    }
+    verify(take(1usize), |i: &Input| {
+        i.as_bytes()[0].is_ascii_alphabetic()
+    })(input)
+}
+
 #[cfg_attr(
     feature = "tracing",
     tracing::instrument(level = "debug", skip(input), fields(input = input.s))
@@ -197,11 +208,20 @@ fn list_item_checkbox(input: Input) -> IResult<Input, (GreenElement, Input), ()>
     tracing::instrument(level = "debug", skip(input), fields(input = input.s))
 )]
 fn list_item_tag(input: Input) -> IResult<Input, (GreenElement, Input), ()> {
-    let bytes = input.as_bytes();
-
-    let (input, tag) = match memchr2(b'\n', b':', bytes) {
-        Some(idx) if idx > 0 && bytes[idx] == b':' => input.take_split(idx),
-        _ => return Err(nom::Err::Error(())),
+    let line = &input.as_str()[..memchr(b'\n', input.as_bytes()).unwrap_or(input.len())];
+
+    // like org-mode, the double colon must be preceded by whitespace and
+    // followed by whitespace or line ending, so colons inside objects, like
+    // footnote references or links with search options, aren't matched
+    let bytes = line.as_bytes();
+    let idx = line.match_indices("::").map(|(idx, _)| idx).find(|&idx| {
+        idx > 0
+            && matches!(bytes[idx - 1], b' ' | b'\t')
+            && matches!(bytes.get(idx + 2), None | Some(b' ' | b'\t' | b'\r'))
+    });
+    let (input, tag) = match idx {
+        Some(idx) => input.take_split(idx),
+        None => return Err(nom::Err::Error(())),
     };
     let (input, ws) = space0(input)?;
     let (input, colon2) = colon2_token(input)?;
@@ -615,3 +635,88 @@ fn parse() {
     assert!(list_node(("*\r\n", config).into()).is_err());
     assert!(list_node(("* ", config).into()).is_err());
 }
+
+#[test]
+fn objects() {
+    use crate::{
+        ast::{Link, List},
+        tests::to_ast,
+    };
+    use rowan::ast::AstNode;
+
+    let to_list = to_ast::<List>(list_node);
+
+    insta::assert_debug_snapshot!(
+        to_list("- [fn:1] [[link]] *bold* :: [fn:1] [[link]] *bold*").syntax,
+        @r###"
+    LIST@0..50
+      LIST_ITEM@0..50
+        LIST_ITEM_INDENT@0..0 ""
+        LIST_ITEM_BULLET@0..2 "- "
+        LIST_ITEM_TAG@2..27
+          FN_REF@2..8
+            L_BRACKET@2..3 "["
+            TEXT@3..5 "fn"
+            COLON@5..6 ":"
+            TEXT@6..7 "1"
+            R_BRACKET@7..8 "]"
+          TEXT@8..9 " "
+          LINK@9..17
+            L_BRACKET2@9..11 "[["
+            LINK_PATH@11..15 "link"
+            R_BRACKET2@15..17 "]]"
+          TEXT@17..18 " "
+          BOLD@18..24
+            STAR@18..19 "*"
+            TEXT@19..23 "bold"
+            STAR@23..24 "*"
+          TEXT@24..25 " "
+          COLON2@25..27 "::"
+        WHITESPACE@27..27 ""
+        LIST_ITEM_CONTENT@27..50
+          PARAGRAPH@27..50
+            TEXT@27..28 " "
+            FN_REF@28..34
+              L_BRACKET@28..29 "["
+              TEXT@29..31 "fn"
+              COLON@31..32 ":"
+              TEXT@32..33 "1"
+              R_BRACKET@33..34 "]"
+            TEXT@34..35 " "
+            LINK@35..43
+              L_BRACKET2@35..37 "[["
+              LINK_PATH@37..41 "link"
+              R_BRACKET2@41..43 "]]"
+            TEXT@43..44 " "
+            BOLD@44..50
+              STAR@44..45 "*"
+              TEXT@45..49 "bold"
+              STAR@49..50 "*"
+    "###
+    );
+
+    // double colons in links with search options don't start a tag
+    let list = to_list("- see [[file:a.org::*h]] here");
+    let item = list.items().next().unwrap();
+    assert!(item.tag_raw().is_none());
+    assert_eq!(item.syntax.descendants().filter_map(Link::cast).count(), 1);
+
+    let list = to_list("- a::b :: c");
+    assert_eq!(list.items().next().unwrap().tag_raw().unwrap(), "a::b");
+    let list = to_list("- term ::\n  description");
+    assert_eq!(list.items().next().unwrap().tag_raw().unwrap(), "term");
+    let list = to_list("- a :: b");
+    assert_eq!(list.items().next().unwrap().tag_raw().unwrap(), "a");
+    assert!(to_list("- a ::b")
+        .items()
+        .next()
+        .unwrap()
+        .tag_raw()
+        .is_none());
+    assert!(to_list("- a:: b")
+        .items()
+        .next()
+        .unwrap()
+        .tag_raw()
+        .is_none());
+}
@@ -242,6 +242,17 @@ impl From<SyntaxKind> for rowan::SyntaxKind {
 
 impl SyntaxKind {
     /// whether this node is [object](https://orgmode.org/worg/org-syntax.html#Objects)
+    ///
+    /// ```rust
+    /// use orgize::{Org, SyntaxKind, ast::Paragraph, rowan::ast::AstNode};
+    ///
+    /// let org = Org::parse("<<target>> <<<radio>>> [[link]] *bold* [fn:1] {{{macro}}}");
+    /// let paragraph = org.first_node::<Paragraph>().unwrap();
+    /// let kinds = paragraph.syntax().children().map(|n| n.kind()).collect::<Vec<_>>();
+    /// assert!(kinds.contains(&SyntaxKind::TARGET));
+    /// assert!(kinds.iter().all(|k| k.is_object()));
+    /// assert!(!SyntaxKind::PARAGRAPH.is_object());
+    /// ```
     pub fn is_object(&self) -> bool {
         matches!(
             self,
@@ -255,6 +266,7 @@ impl SyntaxKind {
                 | SyntaxKind::LINK
                 | SyntaxKind::MACROS
                 | SyntaxKind::RADIO_TARGET
+                | SyntaxKind::TARGET
                 | SyntaxKind::COOKIE
                 | SyntaxKind::SUPERSCRIPT
                 | SyntaxKind::SUBSCRIPT
@@ -319,4 +331,135 @@ impl SyntaxKind {
                 | SyntaxKind::ORG_TABLE
         )
     }
+
+    /// whether this node is a [block](https://orgmode.org/worg/org-syntax.html#Blocks),
+    /// including dynamic block
+    ///
+    /// ```rust
+    /// use orgize::SyntaxKind;
+    ///
+    /// assert!(SyntaxKind::SOURCE_BLOCK.is_block());
+    /// assert!(SyntaxKind::DYN_BLOCK.is_block());
+    /// assert!(!SyntaxKind::DRAWER.is_block());
+    /// assert!(!SyntaxKind::BLOCK_CONTENT.is_block());
+    /// ```
+    pub fn is_block(&self) -> bool {
+        matches!(
+            self,
+            SyntaxKind::CENTER_BLOCK
+                | SyntaxKind::QUOTE_BLOCK
+                | SyntaxKind::SPECIAL_BLOCK
+                | SyntaxKind::COMMENT_BLOCK
+                | SyntaxKind::EXAMPLE_BLOCK
+                | SyntaxKind::EXPORT_BLOCK
+                | SyntaxKind::SOURCE_BLOCK
+                | SyntaxKind::VERSE_BLOCK
+                | SyntaxKind::DYN_BLOCK
+        )
+    }
+
+    /// whether this node is [text markup](https://orgmode.org/worg/org-syntax.html#Emphasis_Markers)
+    ///
+    /// ```rust
+    /// use orgize::SyntaxKind;
+    ///
+    /// assert!(SyntaxKind::BOLD.is_inline_markup());
+    /// assert!(SyntaxKind::CODE.is_inline_markup());
+    /// assert!(!SyntaxKind::LINK.is_inline_markup());
+    /// ```
+    pub fn is_inline_markup(&self) -> bool {
+        matches!(
+            self,
+            SyntaxKind::BOLD
+                | SyntaxKind::ITALIC
+                | SyntaxKind::UNDERLINE
+                | SyntaxKind::VERBATIM
+                | SyntaxKind::CODE
+                | SyntaxKind::STRIKE
+        )
+    }
+
+    /// Returns the type name `org-element` uses for this node, e.g.
+    /// `plain-list` for [`SyntaxKind::LIST`]
+    ///
+    /// Returns `None` for tokens, for nodes that are parts of an element
+    /// or object rather than elements or objects themselves, like
+    /// [`SyntaxKind::HEADLINE_TITLE`], and for affiliated keywords, which
+    /// `org-element` stores as properties of the element they belong to.
+    ///
+    /// Kinds that `org-element` doesn't distinguish map to the same name:
+    /// both table rows are `table-row`, both table types are `table`, and
+    /// all timestamps are `timestamp`.
+    ///
+    /// ```rust
+    /// use orgize::SyntaxKind;
+    ///
+    /// assert_eq!(SyntaxKind::DOCUMENT.as_org_element_name(), Some("org-data"));
+    /// assert_eq!(SyntaxKind::HEADLINE.as_org_element_name(), Some("headline"));
+    /// assert_eq!(SyntaxKind::LIST.as_org_element_name(), Some("plain-list"));
+    /// assert_eq!(SyntaxKind::SOURCE_BLOCK.as_org_element_name(), Some("src-block"));
+    /// assert_eq!(SyntaxKind::TIMESTAMP_INACTIVE.as_org_element_name(), Some("timestamp"));
+    /// assert_eq!(SyntaxKind::STRIKE.as_org_element_name(), Some("strike-through"));
+    /// assert_eq!(SyntaxKind::HEADLINE_TITLE.as_org_element_name(), None);
+    /// assert_eq!(SyntaxKind::TEXT.as_org_element_name(), None);
+    /// ```
+    pub fn as_org_element_name(&self) -> Option<&'static str> {
+        let name = match self {
+            SyntaxKind::DOCUMENT => "org-data",
+            SyntaxKind::SECTION => "section",
+            SyntaxKind::PARAGRAPH => "paragraph",
+            SyntaxKind::HEADLINE => "headline",
+            SyntaxKind::PROPERTY_DRAWER => "property-drawer",
+            SyntaxKind::NODE_PROPERTY => "node-property",
+            SyntaxKind::PLANNING => "planning",
+            SyntaxKind::ORG_TABLE | SyntaxKind::TABLE_EL => "table",
+            SyntaxKind::ORG_TABLE_RULE_ROW | SyntaxKind::ORG_TABLE_STANDARD_ROW => "table-row",
+            SyntaxKind::ORG_TABLE_CELL => "table-cell",
+            SyntaxKind::LIST => "plain-list",
+            SyntaxKind::LIST_ITEM => "item",
+            SyntaxKind::DRAWER => "drawer",
+            SyntaxKind::KEYWORD => "keyword",
+            SyntaxKind::BABEL_CALL => "babel-call",
+            SyntaxKind::CLOCK => "clock",
+            SyntaxKind::FN_DEF => "footnote-definition",
+            SyntaxKind::COMMENT => "comment",
+            SyntaxKind::RULE => "horizontal-rule",
+            SyntaxKind::FIXED_WIDTH => "fixed-width",
+            SyntaxKind::DYN_BLOCK => "dynamic-block",
+            SyntaxKind::SPECIAL_BLOCK => "special-block",
+            SyntaxKind::QUOTE_BLOCK => "quote-block",
+            SyntaxKind::CENTER_BLOCK => "center-block",
+            SyntaxKind::VERSE_BLOCK => "verse-block",
+            SyntaxKind::COMMENT_BLOCK => "comment-block",
+            SyntaxKind::EXAMPLE_BLOCK => "example-block",
+            SyntaxKind::EXPORT_BLOCK => "export-block",
+            SyntaxKind::SOURCE_BLOCK => "src-block",
+            SyntaxKind::LATEX_ENVIRONMENT => "latex-environment",
+            SyntaxKind::INLINE_CALL => "inline-babel-call",
+            SyntaxKind::INLINE_SRC => "inline-src-block",
+            SyntaxKind::LINK => "link",
+            SyntaxKind::LINE_BREAK => "line-break",
+            SyntaxKind::COOKIE => "statistics-cookie",
+            SyntaxKind::RADIO_TARGET => "radio-target",
+            SyntaxKind::FN_REF => "footnote-reference",
+            SyntaxKind::LATEX_FRAGMENT => "latex-fragment",
+            SyntaxKind::MACROS => "macro",
+            SyntaxKind::SNIPPET => "export-snippet",
+            SyntaxKind::TARGET => "target",
+            SyntaxKind::BOLD => "bold",
+            SyntaxKind::STRIKE => "strike-through",
+            SyntaxKind::ITALIC => "italic",
+            SyntaxKind::UNDERLINE => "underline",
+            SyntaxKind::VERBATIM => "verbatim",
+            SyntaxKind::CODE => "code",
+            SyntaxKind::ENTITY => "entity",
+            SyntaxKind::SUPERSCRIPT => "superscript",
+            SyntaxKind::SUBSCRIPT => "subscript",
+            SyntaxKind::TIMESTAMP_ACTIVE
+            | SyntaxKind::TIMESTAMP_INACTIVE
+            | SyntaxKind::TIMESTAMP_DIARY => "timestamp",
+            _ => return None,
+        };
+        Some(name)
+    }
 }
@@ -1,4 +1,5 @@
 use nom::{IResult, InputTake};
+use rowan::NodeOrToken;
 
 use super::{
     combinator::GreenElement,
@@ -13,7 +14,7 @@ use super::{
     input::Input,
     latex_fragment::latex_fragment_node,
     line_break::line_break_node,
-    link::link_node,
+    link::{angle_link_node, link_node, plain_link_node},
     macros::macros_node,
     radio_target::radio_target_node,
     snippet::snippet_node,
@@ -42,8 +43,8 @@ impl ObjectPositions<'_> {
                 b's', /* inline source */
                 b'\\', b'$', /* latex & entity */
                 b'{', /* macros */
-                b'^', /* superscript */
-                b'_'  /* subscript */
+                b'^', /* superscript, '_' for subscript is already included */
+                b':'  /* plain link */
             ),
         }
     }
@@ -179,7 +180,9 @@ pub fn standard_object_nodes(input: Input) -> Vec<GreenElement> {
             b'<' => radio_target_node(i)
                 .or_else(|_| target_node(i))
                 .or_else(|_| timestamp_diary_node(i))
-                .or_else(|_| timestamp_active_node(i)),
+                .or_else(|_| timestamp_active_node(i))
+                .or_else(|_| angle_link_node(i)),
+            b':' => plain_link_node(i, pre),
             b'[' => cookie_node(i)
                 .or_else(|_| link_node(i))
                 .or_else(|_| fn_ref_node(i))
@@ -240,11 +243,19 @@ where
                 input.s
             );
 
-            if let Ok((input, pre)) = parse(input, head) {
+            if let Ok((rest, node)) = parse(input, head) {
+                // some objects, like plain links, start before the position
+                // they are found, so their beginning is taken from the head
+                let node_len: usize = match &node {
+                    NodeOrToken::Node(n) => n.text_len().into(),
+                    NodeOrToken::Token(t) => t.text_len().into(),
+                };
+                let (_, head) = head.take_split(head.len() + input.len() - rest.len() - node_len);
                 if !head.is_empty() {
                     nodes.push(head.text_token())
                 }
-                nodes.push(pre);
+                nodes.push(node);
+                let input = rest;
                 debug_assert!(input.len() < i.len(), "{} < {}", input.len(), i.len());
                 i = input;
                 continue 'l;
@@ -99,3 +99,24 @@ c
     "###
     );
 }
+
+#[test]
+fn crlf() {
+    use crate::{ast::Paragraph, tests::to_ast};
+
+    let to_paragraph = to_ast::<Paragraph>(paragraph_node);
+
+    let paragraph = to_paragraph("a\r\n*b*\r\n\r\n \t\r\nc\r\n");
+    insta::assert_debug_snapshot!(paragraph.syntax, @r###"
+    PARAGRAPH@0..14
+      TEXT@0..3 "a\r\n"
+      BOLD@3..6
+        STAR@3..4 "*"
+        TEXT@4..5 "b"
+        STAR@5..6 "*"
+      TEXT@6..8 "\r\n"
+      BLANK_LINE@8..10 "\r\n"
+      BLANK_LINE@10..14 " \t\r\n"
+    "###);
+    assert_eq!(paragraph.syntax.to_string(), "a\r\n*b*\r\n\r\n \t\r\n");
+}
@@ -1,5 +1,4 @@
 use nom::{
-    bytes::complete::take_while,
     character::complete::{multispace0, space0},
     combinator::iterator,
     sequence::tuple,
@@ -9,13 +8,13 @@ use nom::{
 use super::{
     combinator::{blank_lines, line_ends_iter, node, pipe_token, GreenElement, NodeBuilder},
     input::Input,
-    keyword::tblfm_keyword_nodes,
+    keyword::{affiliated_keyword_nodes, tblfm_keyword_nodes},
     object::standard_object_nodes,
     SyntaxKind::*,
 };
 
 fn org_table_node_base(input: Input) -> IResult<Input, GreenElement, ()> {
-    let mut children = vec![];
+    let (input, mut children) = affiliated_keyword_nodes(input)?;
 
     let mut start = 0;
     for i in line_ends_iter(input.as_str()) {
@@ -59,10 +58,7 @@ fn table_standard_row_node(input: Input) -> Result<GreenElement, nom::Err<()>> {
 
     b.ws(ws);
 
-    let mut it = iterator(
-        input,
-        tuple((pipe_token, multispace0, take_while(|c: char| c != '|'))),
-    );
+    let mut it = iterator(input, tuple((pipe_token, multispace0, cell_content)));
 
     it.for_each(|(pipe, ws, input)| {
         b.push(pipe);
@@ -93,6 +89,21 @@ fn table_standard_row_node(input: Input) -> Result<GreenElement, nom::Err<()>> {
     Ok(b.finish(ORG_TABLE_STANDARD_ROW))
 }
 
+/// Takes everything until the next unescaped vertical bar, so `\|` stays
+/// inside the cell
+fn cell_content(input: Input) -> IResult<Input, Input, ()> {
+    let bytes = input.as_bytes();
+    let mut idx = 0;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'|' => break,
+            b'\\' if bytes.get(idx + 1) == Some(&b'|') => idx += 2,
+            _ => idx += 1,
+        }
+    }
+    Ok(input.take_split(idx))
+}
+
 fn table_el_node_base(input: Input) -> IResult<Input, GreenElement, ()> {
     let mut start = 0;
     for i in line_ends_iter(input.as_str()) {
@@ -236,6 +247,87 @@ r#"|
     );
 }
 
+#[test]
+fn org_table_objects() {
+    use crate::{ast::OrgTable, tests::to_ast};
+
+    let to_org_table = to_ast::<OrgTable>(org_table_node);
+
+    insta::assert_debug_snapshot!(
+        to_org_table("| [fn:1] | [[link]] | *bold* |").syntax,
+        @r###"
+    ORG_TABLE@0..30
+      ORG_TABLE_STANDARD_ROW@0..30
+        PIPE@0..1 "|"
+        WHITESPACE@1..2 " "
+        ORG_TABLE_CELL@2..8
+          FN_REF@2..8
+            L_BRACKET@2..3 "["
+            TEXT@3..5 "fn"
+            COLON@5..6 ":"
+            TEXT@6..7 "1"
+            R_BRACKET@7..8 "]"
+        WHITESPACE@8..9 " "
+        PIPE@9..10 "|"
+        WHITESPACE@10..11 " "
+        ORG_TABLE_CELL@11..19
+          LINK@11..19
+            L_BRACKET2@11..13 "[["
+            LINK_PATH@13..17 "link"
+            R_BRACKET2@17..19 "]]"
+        WHITESPACE@19..20 " "
+        PIPE@20..21 "|"
+        WHITESPACE@21..22 " "
+        ORG_TABLE_CELL@22..28
+          BOLD@22..28
+            STAR@22..23 "*"
+            TEXT@23..27 "bold"
+            STAR@27..28 "*"
+        WHITESPACE@28..29 " "
+        PIPE@29..30 "|"
+    "###
+    );
+}
+
+#[test]
+fn caption() {
+    use crate::{ast::OrgTable, tests::to_ast};
+
+    let to_org_table = to_ast::<OrgTable>(org_table_node);
+
+    insta::assert_debug_snapshot!(
+        to_org_table("#+CAPTION: *bold* caption\n#+NAME: tbl\n| a |\n").syntax,
+        @r###"
+    ORG_TABLE@0..44
+      AFFILIATED_KEYWORD@0..26
+        HASH_PLUS@0..2 "#+"
+        TEXT@2..9 "CAPTION"
+        COLON@9..10 ":"
+        TEXT@10..11 " "
+        BOLD@11..17
+          STAR@11..12 "*"
+          TEXT@12..16 "bold"
+          STAR@16..17 "*"
+        TEXT@17..25 " caption"
+        NEW_LINE@25..26 "\n"
+      AFFILIATED_KEYWORD@26..38
+        HASH_PLUS@26..28 "#+"
+        TEXT@28..32 "NAME"
+        COLON@32..33 ":"
+        TEXT@33..37 " tbl"
+        NEW_LINE@37..38 "\n"
+      ORG_TABLE_STANDARD_ROW@38..44
+        PIPE@38..39 "|"
+        WHITESPACE@39..40 " "
+        ORG_TABLE_CELL@40..41
+          TEXT@40..41 "a"
+        WHITESPACE@41..42 " "
+        PIPE@42..43 "|"
+        WHITESPACE@43..44 "\n"
+    "###
+    );
+}
+
 #[test]
 fn parse_table_el() {
     use crate::{ast::TableEl, tests::to_ast, ParseConfig};
@@ -263,3 +355,33 @@ fn parse_table_el() {
     assert!(table_el_node(("", config).into()).is_err());
     assert!(table_el_node(("+----|---", config).into()).is_err());
 }
+
+#[test]
+fn escaped_pipe() {
+    use crate::{ast::OrgTable, tests::to_ast};
+
+    let to_org_table = to_ast::<OrgTable>(org_table_node);
+
+    insta::assert_debug_snapshot!(
+        to_org_table("| ~a \\| b~ | c\\|d |\n").syntax,
+        @r###"
+    ORG_TABLE@0..20
+      ORG_TABLE_STANDARD_ROW@0..20
+        PIPE@0..1 "|"
+        WHITESPACE@1..2 " "
+        ORG_TABLE_CELL@2..10
+          CODE@2..10
+            TILDE@2..3 "~"
+            TEXT@3..9 "a \\| b"
+            TILDE@9..10 "~"
+        WHITESPACE@10..11 " "
+        PIPE@11..12 "|"
+        WHITESPACE@12..13 " "
+        ORG_TABLE_CELL@13..17
+          TEXT@13..17 "c\\|d"
+        WHITESPACE@17..18 " "
+        PIPE@18..19 "|"
+        WHITESPACE@19..20 "\n"
+    "###
+    );
+}
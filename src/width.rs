@@ -0,0 +1,72 @@
+//! Measure how many terminal columns a string occupies.
+//!
+//! Aligning plain text, like the pipes of a table, needs the display width
+//! of each cell rather than its byte length or `char` count: east asian
+//! wide characters take two columns and combining marks take none.
+//!
+//! With the `unicode-width` feature enabled, widths are looked up in the
+//! tables of the [`unicode-width`](https://docs.rs/unicode-width) crate.
+//! Otherwise a small built-in approximation is used, which covers the
+//! common CJK blocks, fullwidth forms and combining marks.
+
+/// Returns the display width of `s`, in columns
+///
+/// ```rust
+/// use orgize::width::display_width;
+///
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("日本語"), 6);
+/// assert_eq!(display_width("ｱｲｳ"), 3);
+/// assert_eq!(display_width("e\u{301}"), 1);
+/// ```
+pub fn display_width(s: &str) -> usize {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "unicode-width")] {
+            unicode_width::UnicodeWidthStr::width(s)
+        } else {
+            s.chars().map(char_width).sum()
+        }
+    }
+}
+
+#[cfg(not(feature = "unicode-width"))]
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        // control characters
+        0..=0x1F | 0x7F..=0x9F => 0,
+        // combining marks, zero width spaces and joiners, variation selectors
+        0x0300..=0x036F
+        | 0x1AB0..=0x1AFF
+        | 0x1DC0..=0x1DFF
+        | 0x200B..=0x200F
+        | 0x20D0..=0x20FF
+        | 0xFE00..=0xFE0F
+        | 0xFE20..=0xFE2F
+        | 0xE0100..=0xE01EF => 0,
+        // hangul jamo
+        0x1100..=0x115F
+        // cjk radicals, kana, cjk symbols and unified ideographs
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        // hangul syllables
+        | 0xAC00..=0xD7A3
+        // cjk compatibility ideographs
+        | 0xF900..=0xFAFF
+        // vertical and compatibility forms
+        | 0xFE10..=0xFE19
+        | 0xFE30..=0xFE6F
+        // fullwidth forms
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        // emoji
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        // supplementary ideographic planes
+        | 0x20000..=0x2FFFD
+        | 0x30000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
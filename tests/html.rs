@@ -1,4 +1,4 @@
-use orgize::Org;
+use orgize::{Org, ParseConfig};
 
 #[test]
 fn emphasis() {
@@ -19,6 +19,16 @@ fn link() {
     );
 }
 
+#[test]
+fn link_abbreviation() {
+    insta::assert_snapshot!(
+        Org::parse("#+LINK: gh https://github.com/%s\n\n[[gh:Xuanwo/orgize]] [[gh:Xuanwo][Xuanwo]]").to_html(),
+        @r###"
+    <main><section><p><a href="https://github.com/Xuanwo/orgize">https://github.com/Xuanwo/orgize</a> <a href="https://github.com/Xuanwo">Xuanwo</a></p></section></main>
+    "###
+    );
+}
+
 #[test]
 fn section_and_headline() {
     insta::assert_snapshot!(
@@ -65,6 +75,13 @@ fn list() {
     </p></li></ul></section></main>
     "###
     );
+
+    insta::assert_snapshot!(
+        Org::parse("- see [[file:a.org::*h]] here").to_html(),
+        @r###"
+    <main><section><ul><li><p>see <a href="a.org::*h">a.org::*h</a> here</p></li></ul></section></main>
+    "###
+    );
 }
 
 #[test]
@@ -155,6 +172,19 @@ fn table() {
         @"<main><section><table></table></section></main>"
     );
 
+    // alignment cookies
+    insta::assert_snapshot!(
+        Org::parse(r#"
+| <l>  | <c> | <10> | <r> |
+| name | a   | b    | 1   |
+|------+-----+------+-----|
+| c    | d   | e    | 10  |
+"#).to_html(),
+        @r###"
+    <main><section><table><thead><tr><td class="org-left">name</td><td class="org-center">a</td><td>b</td><td class="org-right">1</td></tr></thead><tbody><tr><td class="org-left">c</td><td class="org-center">d</td><td>e</td><td class="org-right">10</td></tr></tbody></table></section></main>
+    "###
+    );
+
     insta::assert_snapshot!(
         Org::parse(r#"
 |
@@ -165,6 +195,14 @@ fn table() {
 "#).to_html(),
         @"<main><section><table><thead><tr></tr></thead><tbody><tr></tr></tbody><tbody><tr></tr></tbody></table></section></main>"
     );
+
+    // escaped vertical bar
+    insta::assert_snapshot!(
+        Org::parse("| ~a \\| b~ | c\\|d |").to_html(),
+        @r###"
+    <main><section><table><tbody><tr><td><code>a | b</code></td><td>c|d</td></tr></tbody></table></section></main>
+    "###
+    );
 }
 
 #[test]
@@ -174,3 +212,245 @@ fn line_break() {
         @r###""<main><section><p>aa<br/>bb</p></section></main>""###
     );
 }
+
+#[test]
+fn drawer() {
+    use orgize::export::{HtmlDrawers, HtmlExport};
+
+    let org = Org::parse(
+        r#"* title
+:PROPERTIES:
+:ID: 1
+:END:
+:LOGBOOK:
+CLOCK: [2024-01-01 Mon 10:00]
+:END:
+:NOTES:
+secret
+:END:
+text"#,
+    );
+
+    insta::assert_snapshot!(
+        org.to_html(),
+        @"<main><h1>title</h1><section><p>text</p></section></main>"
+    );
+
+    let mut html = HtmlExport::default().with_drawers(HtmlDrawers::All);
+    org.traverse(&mut html);
+    insta::assert_snapshot!(
+        html.finish(),
+        @r###"
+    <main><h1>title</h1><section><div class="drawer"></div><div class="drawer"><p>secret
+    </p></div><p>text</p></section></main>
+    "###
+    );
+}
+
+#[test]
+fn verbatim_and_code() {
+    insta::assert_snapshot!(
+        Org::parse("~a < b~ and =x & y=").to_html(),
+        @r###"
+    <main><section><p><code>a &lt; b</code> and <code>x &amp; y</code></p></section></main>
+    "###
+    );
+
+    // contents are not parsed
+    insta::assert_snapshot!(
+        Org::parse("=*bold* /italic/ <b>= ~\\alpha [[link]] a^b~").to_html(),
+        @r###"
+    <main><section><p><code>*bold* /italic/ &lt;b&gt;</code> <code>\alpha [[link]] a^b</code></p></section></main>
+    "###
+    );
+}
+
+#[test]
+fn line_numbers_and_coderefs() {
+    insta::assert_snapshot!(
+        Org::parse(r#"#+BEGIN_SRC rust -n -r
+fn main() {
+    let a = 1; (ref:init)
+}
+#+END_SRC
+
+#+BEGIN_EXAMPLE +n 10
+a < b (ref:cmp)
+#+END_EXAMPLE
+
+See line [[(init)]] and [[(cmp)][comparison]]."#).to_html(),
+        @r###"
+    <main><section><pre><code class="language-rust"><span class="linenr">1: </span>fn main() {
+    <span class="linenr">2: </span><span id="coderef-init" class="coderef-off">    let a = 1;</span>
+    <span class="linenr">3: </span>}
+    </code></pre><pre class="example"><span class="linenr">14: </span><span id="coderef-cmp" class="coderef-off">a &lt; b (ref:cmp)</span>
+    </pre><p>See line <a href="#coderef-init">2</a> and <a href="#coderef-cmp">comparison</a>.</p></section></main>
+    "###
+    );
+}
+
+#[test]
+fn reuse_exporter() {
+    use orgize::export::HtmlExport;
+
+    let org = Org::parse(
+        r#"#+CAPTION: Data
+#+NAME: tbl
+| a |
+
+See [[tbl]].
+
+#+BEGIN_SRC rust +n
+fn main() {}
+#+END_SRC"#,
+    );
+
+    let mut html = HtmlExport::default();
+    org.traverse(&mut html);
+    let first = html.finish();
+
+    // numbering and references start over in every document
+    let mut html = HtmlExport::default();
+    org.traverse(&mut html);
+    Org::parse("See [[tbl]].\n\n#+NAME: tbl\n[[./a.png]]").traverse(&mut html);
+    org.traverse(&mut html);
+    let output = html.finish();
+
+    assert!(output.ends_with(&first));
+    insta::assert_snapshot!(
+        output.strip_suffix(&first).unwrap().strip_prefix(&first).unwrap(),
+        @r###"
+    <main><section><p>See <a href="#tbl">tbl</a>.
+    </p><a id="tbl"></a><p><img src="./a.png"></p></section></main>
+    "###
+    );
+}
+
+#[test]
+fn alphabetical_list() {
+    let config = ParseConfig {
+        alphabetical_lists: true,
+        ..Default::default()
+    };
+
+    insta::assert_snapshot!(
+        config.parse("a. one\nb. two\n\n\nA) three").to_html(),
+        @r###"
+    <main><section><ol type="a"><li><p>one
+    </p></li><li><p>two
+    </p></li></ol><ol type="A"><li><p>three</p></li></ol></section></main>
+    "###
+    );
+
+    insta::assert_snapshot!(
+        Org::parse("a. one\nb. two").to_html(),
+        @r###"
+    <main><section><p>a. one
+    b. two</p></section></main>
+    "###
+    );
+}
+
+#[test]
+fn plain_and_angle_links() {
+    insta::assert_snapshot!(
+        Org::parse("see https://orgmode.org, <mailto:a@b.c> and *[[https://example.com][bracket]]*").to_html(),
+        @r###"
+    <main><section><p>see <a href="https://orgmode.org">https://orgmode.org</a>, <a href="mailto:a@b.c">mailto:a@b.c</a> and <b><a href="https://example.com">bracket</a></b></p></section></main>
+    "###
+    );
+}
+
+#[test]
+fn caption() {
+    insta::assert_snapshot!(
+        Org::parse(r#"See [[tbl]], [[fig][this figure]] and [[unknown]].
+
+#+CAPTION: Some *data*
+#+NAME: tbl
+| a |
+|---|
+| 1 |
+
+#+CAPTION: A /cat/
+#+NAME: fig
+[[file:cat.png]]
+
+#+CAPTION: Unnamed
+[[./dog.jpg]]
+
+#+CAPTION: Not a figure
+text [[./bird.jpg]]
+"#).to_html(),
+        @r###"
+    <main><section><p>See <a href="#tbl">Table 1</a>, <a href="#fig">this figure</a> and <a href="unknown">unknown</a>.
+    </p><table id="tbl"><caption>Table 1: Some <b>data</b></caption><thead><tr><td>a</td></tr></thead><tbody><tr><td>1</td></tr></tbody></table><figure id="fig"><img src="cat.png"><figcaption>Figure 1: A <i>cat</i></figcaption></figure><figure><img src="./dog.jpg"><figcaption>Figure 2: Unnamed</figcaption></figure><p>text <img src="./bird.jpg">
+    </p></section></main>
+    "###
+    );
+}
+
+#[test]
+fn named_elements() {
+    insta::assert_snapshot!(
+        Org::parse(r#"See [[code]], [[tbl][the table]] and [[*code]].
+
+#+NAME: code
+#+BEGIN_SRC rust
+fn main() {}
+#+END_SRC
+
+#+NAME: tbl
+| a |
+
+* code
+"#).to_html(),
+        @r###"
+    <main><section><p>See <a href="#code">code</a>, <a href="#tbl">the table</a> and <a href="*code">*code</a>.
+    </p><a id="code"></a><pre><code class="language-rust">fn main() {}
+    </code></pre><a id="tbl"></a><table><tbody><tr><td>a</td></tr></tbody></table></section><h1>code</h1></main>
+    "###
+    );
+}
+
+#[test]
+fn heading_ids() {
+    use orgize::export::{HtmlExport, HtmlHeadingIds};
+
+    let mut html = HtmlExport::default().with_heading_ids(HtmlHeadingIds::Slug);
+    Org::parse("* Intro\n* Intro\n** Intro").traverse(&mut html);
+    insta::assert_snapshot!(
+        html.finish(),
+        @r###"
+    <main><h1 id="intro">Intro</h1><h1 id="intro-1">Intro</h1><h2 id="intro-2">Intro</h2></main>
+    "###
+    );
+}
+
+#[test]
+fn options() {
+    let html = Org::parse("#+OPTIONS: toc:nil ^:nil\na^b c_{d}").to_html();
+    assert!(!html.contains("<sup>"));
+    assert!(!html.contains("<sub>"));
+    insta::assert_snapshot!(html, @r###"
+    <main><section><p>a^b c_{d}</p></section></main>
+    "###);
+
+    insta::assert_snapshot!(
+        Org::parse("#+OPTIONS: ^:{}\na^b c_{d}").to_html(),
+        @r###"
+    <main><section><p>a^b c<sub>d</sub></p></section></main>
+    "###
+    );
+
+    insta::assert_snapshot!(
+        Org::parse("#+OPTIONS: \\n:t\nline *one\ntwo*\nline three\n\nline four\n").to_html(),
+        @r###"
+    <main><section><p>line <b>one<br/>
+    two</b><br/>
+    line three
+    </p><p>line four
+    </p></section></main>
+    "###
+    );
+}
@@ -0,0 +1,106 @@
+use orgize::{export::MarkdownExport, Org};
+
+fn to_markdown(s: &str) -> String {
+    let mut markdown = MarkdownExport::default();
+    Org::parse(s).traverse(&mut markdown);
+    markdown.finish()
+}
+
+#[test]
+fn table() {
+    insta::assert_snapshot!(
+        to_markdown(r#"
+|---+---------+-----|
+| a | *b*     | c   |
+|---+---------+-----|
+|   | <c>     | <r> |
+| 1 | =x=     | 3   |
+| 4 | 5       |
+|---+---------+-----|
+"#),
+        @r###"
+    | a | **b** | c |
+    | --- | :---: | ---: |
+    | 1 | `x` | 3 |
+    | 4 | 5 |  |
+    "###
+    );
+
+    insta::assert_snapshot!(
+        to_markdown("| a | b |\n| c | d |"),
+        @r###"
+    |  |  |
+    | --- | --- |
+    | a | b |
+    | c | d |
+    "###
+    );
+
+    insta::assert_snapshot!(
+        to_markdown("| <l> | <r10> |\n| a | b |\n| c | d |\n|---|\n| e | f |"),
+        @r###"
+    | a | b |
+    | :--- | ---: |
+    | c | d |
+    | e | f |
+    "###
+    );
+
+    insta::assert_snapshot!(
+        to_markdown("| ~a \\| b~ | c\\|d |"),
+        @r###"
+    |  |  |
+    | --- | --- |
+    | `a \| b` | c\|d |
+    "###
+    );
+}
+
+#[test]
+fn blocks() {
+    insta::assert_snapshot!(
+        to_markdown(r#"******** Deep *bold* /italic/ ~code~ =verbatim=
+
+#+BEGIN_SRC rust
+fn main() {
+,* not a headline
+}
+#+END_SRC
+
+#+BEGIN_EXAMPLE
+example
+#+END_EXAMPLE
+
+#+BEGIN_EXPORT latex
+\newpage
+#+END_EXPORT
+
+#+BEGIN_EXPORT markdown
+<kbd>Ctrl</kbd>
+#+END_EXPORT
+"#),
+        @r###"
+    ###### Deep **bold** *italic* `code` `verbatim`
+
+    ```rust
+    fn main() {
+    * not a headline
+    }
+    ```
+    ```
+    example
+    ```
+    <kbd>Ctrl</kbd>
+    "###
+    );
+}
+
+#[test]
+fn subscript_superscript() {
+    insta::assert_snapshot!(
+        to_markdown("a_1 x^{2} H_{2}O e^{i*pi}"),
+        @r###"
+    a_1 x^{2} H_{2}O e^{i*pi}
+    "###
+    );
+}